@@ -1,6 +1,7 @@
 use crate::commands::profiles::load_full_profile;
 use crate::db::connection::create_client;
-use crate::db::queries::fetch_user_databases;
+use crate::db::queries::{fetch_login_name, fetch_session_auth, fetch_user_databases};
+use crate::models::types::{ConnectionTestResult, PatternFilter, UserDatabase};
 use crate::AppState;
 use tauri::State;
 
@@ -12,14 +13,18 @@ pub async fn test_connection(
     app: tauri::AppHandle,
     state: State<'_, AppState>,
     profile_id: String,
-) -> Result<(), String> {
+) -> Result<ConnectionTestResult, String> {
     let profile = load_full_profile(&app, &state.profile_io_lock, &profile_id).await?;
-    let mut client = create_client(&profile, Some("master"), DEFAULT_CONNECT_TIMEOUT_MS).await?;
-    client
-        .simple_query("SELECT 1")
+    let mut client = create_client(&profile, Some("master"), DEFAULT_CONNECT_TIMEOUT_MS)
         .await
         .map_err(|e| e.to_string())?;
-    Ok(())
+    // sys.dm_exec_connections needs VIEW SERVER STATE; without it the connection
+    // still works and only the scheme is unknown.
+    let (auth_scheme, login_name) = match fetch_session_auth(&mut client).await {
+        Ok((scheme, login)) => (Some(scheme), login),
+        Err(_) => (None, fetch_login_name(&mut client).await.map_err(|e| e.to_string())?),
+    };
+    Ok(ConnectionTestResult { auth_type: profile.auth_type, auth_scheme, login_name })
 }
 
 #[specta::specta]
@@ -28,8 +33,13 @@ pub async fn get_databases(
     app: tauri::AppHandle,
     state: State<'_, AppState>,
     profile_id: String,
-) -> Result<Vec<String>, String> {
+    database_filter: Option<PatternFilter>,
+) -> Result<Vec<UserDatabase>, String> {
     let profile = load_full_profile(&app, &state.profile_io_lock, &profile_id).await?;
-    let mut client = create_client(&profile, Some("master"), DEFAULT_CONNECT_TIMEOUT_MS).await?;
-    fetch_user_databases(&mut client).await
+    let mut client = create_client(&profile, Some("master"), DEFAULT_CONNECT_TIMEOUT_MS)
+        .await
+        .map_err(|e| e.to_string())?;
+    fetch_user_databases(&mut client, &database_filter.unwrap_or_default())
+        .await
+        .map_err(|e| e.to_string())
 }
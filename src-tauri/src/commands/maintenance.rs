@@ -1,21 +1,36 @@
 use crate::commands::profiles::load_full_profile;
-use crate::db::connection::create_client;
+use crate::db::connection::create_maintenance_client;
+use crate::db::error::SqlError;
+use crate::db::filters::{
+    apply_index_policy, database_options, database_rejection, statistics_rejection, table_options,
+};
+use crate::db::history::{
+    duration_key, index_duration_estimates, index_key, index_last_maintained,
+};
+use crate::db::script::{build_plan_script, script_file_name};
 use crate::db::queries::{
-    fetch_fragmented_indexes, rebuild_index_sql, reorganize_index_sql, update_statistics_sql,
-    FREE_PROC_CACHE,
+    fetch_ag_queues, fetch_blocking, fetch_fragmented_indexes, fetch_index_usage,
+    fetch_log_space_usage, fetch_paused_resumable_operations, fetch_replica_roles,
+    fetch_request_progress, fetch_server_info, fetch_session_id, fetch_statistics,
+    pause_index_sql, rebuild_heap_sql, rebuild_index_sql, reorganize_columnstore_sql,
+    reorganize_index_sql, resume_index_sql, update_statistics_sql, update_table_statistics_sql,
+    LowPriorityWait, RebuildOptions, FREE_PROC_CACHE,
 };
 use crate::models::types::{
-    DatabaseResult, IndexInfo, IndexResult, MaintenanceAction, MaintenanceOptions,
-    MaintenanceSummary, ServerProfile,
+    AbortAfterWait, AgQueues, BlockingSession, BlockingSnapshot, ColumnstoreStats, DataCompression, DatabasePlan, DatabaseResult,
+    IndexInfo, IndexKind, IndexOutcome, IndexPlan, IndexProgress, IndexPriority, IndexResult, LogSpaceUsage,
+    LogUsageAction, MaintenanceAction, MaintenanceOptions, MaintenancePlan, MaintenanceSummary,
+    OnlineFallback, ReplicaRole, ServerInfo, ServerProfile, StatisticsInfo, StatisticsPlan,
+    StatisticsResult, StatisticsSampling,
 };
 use crate::{AppState, MaintenanceControl, ProfileControl};
 use serde::Serialize;
 use specta::Type;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use tauri::{AppHandle, Emitter, State};
-use tokio::sync::{watch, Mutex};
-use tokio::time::{sleep, timeout, Duration};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::sync::{watch, Mutex, OnceCell};
+use tokio::time::{sleep, sleep_until, timeout, Duration, Instant};
 
 // ---------------------------------------------------------------------------
 // Typed event payloads — all fields are owned (no lifetimes) for specta compat.
@@ -48,6 +63,7 @@ pub struct IndexActionEvent {
     pub schema_name: String,
     pub table_name: String,
     pub index_name: String,
+    pub partition_number: Option<i32>,
     pub action: MaintenanceAction,
 }
 
@@ -58,11 +74,54 @@ pub struct IndexCompleteEvent {
     pub schema_name: String,
     pub table_name: String,
     pub index_name: String,
+    pub partition_number: Option<i32>,
     pub action: MaintenanceAction,
     pub success: bool,
+    pub outcome: IndexOutcome,
     pub duration_secs: f64,
     pub retry_attempts: u32,
     pub error: Option<String>,
+    pub reason: Option<String>,
+}
+
+#[derive(Serialize, Clone, Type)]
+pub struct StatisticsCompleteEvent {
+    pub profile_id: String,
+    pub db_name: String,
+    pub result: StatisticsResult,
+}
+
+#[derive(Serialize, Clone, Type)]
+pub struct LogUsageEvent {
+    pub profile_id: String,
+    pub db_name: String,
+    /// `waiting`, `paused`, or `cleared` once usage is back under the limit.
+    pub state: String,
+    pub usage: LogSpaceUsage,
+    pub max_percent: f64,
+}
+
+#[derive(Serialize, Clone, Type)]
+pub struct AgThrottleEvent {
+    pub profile_id: String,
+    pub db_name: String,
+    /// `waiting`, or `cleared` once the queues are back under their limits.
+    pub state: String,
+    pub queues: AgQueues,
+    pub max_log_send_queue_mb: u32,
+    pub max_redo_queue_mb: u32,
+}
+
+/// Sent periodically while an index operation runs.
+#[derive(Serialize, Clone, Type)]
+pub struct IndexProgressEvent {
+    pub profile_id: String,
+    pub db_name: String,
+    pub schema_name: String,
+    pub table_name: String,
+    pub index_name: String,
+    pub partition_number: Option<i32>,
+    pub progress: IndexProgress,
 }
 
 #[derive(Serialize, Clone, Type)]
@@ -87,6 +146,7 @@ pub struct MaintenanceErrorEvent {
 // Maintenance context — groups shared state to avoid too-many-arguments
 // ---------------------------------------------------------------------------
 
+#[derive(Clone)]
 struct MaintenanceCtx {
     app: AppHandle,
     control_txs: Arc<Mutex<HashMap<String, ProfileControl>>>,
@@ -96,6 +156,18 @@ struct MaintenanceCtx {
     profile: ServerProfile,
     options: MaintenanceOptions,
     skip_set: Arc<Mutex<HashSet<String>>>,
+    /// Detected on the first database connection and shared by the rest of the run.
+    server: Arc<OnceCell<ServerInfo>>,
+    /// When the maintenance window closes; `None` without a window.
+    deadline: Option<Instant>,
+    /// Past durations of index operations keyed by [`duration_key`]; only
+    /// loaded when the run has a deadline.
+    duration_estimates: Arc<HashMap<String, f64>>,
+    /// When each index was last maintained by this profile, keyed by [`index_key`];
+    /// only loaded for [`IndexPriority::LeastRecentlyMaintained`].
+    last_maintained: Arc<HashMap<String, String>>,
+    /// Availability Group role and group name per database, read once per run.
+    replica_roles: Arc<OnceCell<HashMap<String, (ReplicaRole, String)>>>,
 }
 
 // ---------------------------------------------------------------------------
@@ -148,6 +220,31 @@ async fn wait_for_stop(ctrl_rx: &watch::Receiver<MaintenanceControl>) {
     }
 }
 
+/// Resolves when the run is paused or stopped (or the sender is dropped).
+async fn wait_for_pause_or_stop(ctrl_rx: &watch::Receiver<MaintenanceControl>) {
+    let mut rx = ctrl_rx.clone();
+    loop {
+        if *rx.borrow_and_update() != MaintenanceControl::Running {
+            return;
+        }
+        if rx.changed().await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Resolves when the maintenance window closes; never without a deadline.
+async fn wait_for_deadline(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+fn window_closed(deadline: Option<Instant>) -> bool {
+    deadline.is_some_and(|d| Instant::now() >= d)
+}
+
 /// Resolves when `db_name` is present in `skip_set`. Polls every 200 ms so the
 /// mutex is not held continuously while a long SQL operation runs.
 async fn poll_skip_set(skip_set: &Arc<Mutex<HashSet<String>>>, db_name: &str) {
@@ -180,27 +277,789 @@ async fn wait_delay_with_ctrl(
 // Fragmentation thresholds
 // ---------------------------------------------------------------------------
 
-fn determine_action(
-    fragmentation: f64,
-    reorganize_threshold: f64,
-    rebuild_threshold: f64,
-) -> MaintenanceAction {
+fn determine_action(index: &IndexInfo, options: &MaintenanceOptions) -> MaintenanceAction {
+    if index.index_kind == IndexKind::Heap {
+        return determine_heap_action(index, options);
+    }
+    if let Some(columnstore) = &index.columnstore {
+        return determine_columnstore_action(index, columnstore, options);
+    }
     // Ensure rebuild_threshold >= reorganize_threshold even if user misconfigured
-    let effective_rebuild = rebuild_threshold.max(reorganize_threshold);
-    if fragmentation >= effective_rebuild {
-        MaintenanceAction::Rebuild
-    } else if fragmentation >= reorganize_threshold {
+    let effective_rebuild = options.rebuild_threshold.max(options.reorganize_threshold);
+    if index.fragmentation_percent >= effective_rebuild {
+        return MaintenanceAction::Rebuild;
+    }
+    // Page density is only reported by SAMPLED/DETAILED scans; a sparse leaf level
+    // wastes buffer pool and I/O even when logical fragmentation is low.
+    if let Some(density) = index.page_density_percent {
+        if options.page_density_threshold > 0.0 && density < options.page_density_threshold {
+            return MaintenanceAction::Rebuild;
+        }
+    }
+    if index.fragmentation_percent >= options.reorganize_threshold {
         MaintenanceAction::Reorganize
     } else {
         MaintenanceAction::Skip
     }
 }
 
-fn is_transient_error(err: &str) -> bool {
-    let lower = err.to_lowercase();
-    ["timeout", "connection", "deadlock", "throttl", "busy", "reset"]
-        .iter()
-        .any(|pat| lower.contains(pat))
+/// Heaps cannot be reorganized; they are rebuilt when forwarded records or
+/// extent fragmentation cross their (independently disabled) thresholds.
+fn determine_heap_action(index: &IndexInfo, options: &MaintenanceOptions) -> MaintenanceAction {
+    let forwarded_percent = match (index.forwarded_record_count, index.record_count) {
+        (Some(forwarded), Some(records)) if records > 0 => forwarded as f64 * 100.0 / records as f64,
+        _ => 0.0,
+    };
+    if options.heap_forwarded_record_threshold > 0.0
+        && forwarded_percent >= options.heap_forwarded_record_threshold
+    {
+        return MaintenanceAction::RebuildHeap;
+    }
+    if options.heap_fragmentation_threshold > 0.0
+        && index.fragmentation_percent >= options.heap_fragmentation_threshold
+    {
+        return MaintenanceAction::RebuildHeap;
+    }
+    MaintenanceAction::Skip
+}
+
+/// Columnstore indexes are judged by their deleted-row ratio (carried in
+/// `fragmentation_percent`) and by delta rowgroups the tuple mover has not
+/// compressed yet; REORGANIZE handles both far more cheaply than REBUILD.
+fn determine_columnstore_action(
+    index: &IndexInfo,
+    columnstore: &ColumnstoreStats,
+    options: &MaintenanceOptions,
+) -> MaintenanceAction {
+    let effective_rebuild = options
+        .columnstore_rebuild_threshold
+        .max(options.columnstore_reorganize_threshold);
+    if index.fragmentation_percent >= effective_rebuild {
+        return MaintenanceAction::Rebuild;
+    }
+    let delta_row_groups = columnstore.open_row_groups + columnstore.closed_row_groups;
+    if index.fragmentation_percent >= options.columnstore_reorganize_threshold
+        || (options.columnstore_delta_rowgroup_threshold > 0
+            && delta_row_groups >= options.columnstore_delta_rowgroup_threshold)
+    {
+        MaintenanceAction::Reorganize
+    } else {
+        MaintenanceAction::Skip
+    }
+}
+
+/// Final decision for an index once server capabilities are taken into account.
+struct IndexDecision {
+    action: MaintenanceAction,
+    /// `ONLINE` setting for rebuilds.
+    online: bool,
+    /// `RESUMABLE = ON` for online rebuilds.
+    resumable: bool,
+    reason: Option<String>,
+}
+
+fn decide_index(index: &IndexInfo, options: &MaintenanceOptions, server: &ServerInfo) -> IndexDecision {
+    if let Some(excluded) = &index.excluded_by {
+        return IndexDecision {
+            action: MaintenanceAction::Skip,
+            online: false,
+            resumable: false,
+            reason: Some(excluded.clone()),
+        };
+    }
+    if let Some(percent) = index.paused_rebuild_percent {
+        return IndexDecision {
+            action: MaintenanceAction::Rebuild,
+            online: true,
+            resumable: true,
+            reason: Some(format!("Resumed paused rebuild at {:.1}%", percent)),
+        };
+    }
+    let action = determine_action(index, options);
+    if options.reorganize_only {
+        match action {
+            MaintenanceAction::Rebuild => {
+                return IndexDecision {
+                    action: MaintenanceAction::Reorganize,
+                    online: false,
+                    resumable: false,
+                    reason: Some("Past the rebuild threshold; reorganized (reorganize only)".to_string()),
+                };
+            }
+            MaintenanceAction::RebuildHeap => {
+                return IndexDecision {
+                    action: MaintenanceAction::Skip,
+                    online: false,
+                    resumable: false,
+                    reason: Some("Heaps cannot be reorganized; skipped (reorganize only)".to_string()),
+                };
+            }
+            _ => {}
+        }
+    }
+    let is_rebuild = matches!(action, MaintenanceAction::Rebuild | MaintenanceAction::RebuildHeap);
+    if !is_rebuild || !options.rebuild_online {
+        return IndexDecision { action, online: false, resumable: false, reason: None };
+    }
+    match online_rebuild_blocker(index, server) {
+        None => IndexDecision {
+            resumable: options.resumable_rebuild && supports_resumable_rebuild(index, server),
+            action,
+            online: true,
+            reason: None,
+        },
+        Some(blocker) => match options.online_fallback {
+            OnlineFallback::Offline => IndexDecision {
+                action,
+                online: false,
+                resumable: false,
+                reason: Some(format!("{}; rebuilt offline", blocker)),
+            },
+            OnlineFallback::Skip => IndexDecision {
+                action: MaintenanceAction::Skip,
+                online: false,
+                resumable: false,
+                reason: Some(format!("{}; skipped by online fallback policy", blocker)),
+            },
+        },
+    }
+}
+
+/// Resumable rebuilds need SQL Server 2017 and only cover whole rowstore
+/// indexes — not heaps, columnstore, XML or spatial indexes, or single partitions.
+fn supports_resumable_rebuild(index: &IndexInfo, server: &ServerInfo) -> bool {
+    server.at_least(14)
+        && index.index_kind == IndexKind::Rowstore
+        && index.target_partition().is_none()
+}
+
+/// Why `ONLINE = ON` would fail for this index on this server, if it would.
+fn online_rebuild_blocker(index: &IndexInfo, server: &ServerInfo) -> Option<String> {
+    if !server.supports_online_rebuild() {
+        return Some(format!("Online rebuild is not available on {}", server.edition));
+    }
+    match index.index_kind {
+        IndexKind::Xml | IndexKind::Spatial => {
+            return Some("XML and spatial indexes cannot be rebuilt online".to_string());
+        }
+        IndexKind::ClusteredColumnstore | IndexKind::NonclusteredColumnstore
+            if !server.at_least(15) =>
+        {
+            return Some("Online columnstore rebuild requires SQL Server 2019".to_string());
+        }
+        _ => {}
+    }
+    if index.has_legacy_lob {
+        return Some("Text, ntext and image columns cannot be rebuilt online".to_string());
+    }
+    if index.has_max_lob && !server.at_least(11) {
+        return Some("Online rebuild with max or xml columns requires SQL Server 2012".to_string());
+    }
+    if index.target_partition().is_some() && !server.at_least(12) {
+        return Some("Online single-partition rebuild requires SQL Server 2014".to_string());
+    }
+    None
+}
+
+fn low_priority_wait(options: &MaintenanceOptions) -> Option<LowPriorityWait> {
+    options.low_priority_wait.then_some(LowPriorityWait {
+        max_duration_minutes: options.low_priority_max_duration_minutes,
+        abort_after_wait: options.abort_after_wait,
+    })
+}
+
+/// Whether a lock timeout on this operation means its low-priority wait gave up.
+fn aborts_itself_at_low_priority(decision_online: bool, options: &MaintenanceOptions) -> bool {
+    decision_online
+        && options.low_priority_wait
+        && options.abort_after_wait == AbortAfterWait::SelfAbort
+}
+
+/// `WITH (...)` settings for rebuilding `index`. Columnstore indexes only take
+/// MAXDOP; heaps have no fill factor; compression is only set on rowstore data.
+fn rebuild_options(
+    index: &IndexInfo,
+    decision: &IndexDecision,
+    options: &MaintenanceOptions,
+) -> RebuildOptions {
+    let mut rebuild = RebuildOptions {
+        online: decision.online,
+        resumable: decision.resumable,
+        max_duration_minutes: options.resumable_max_duration_minutes,
+        maxdop: options.maxdop,
+        sort_in_tempdb: options.sort_in_tempdb,
+        fill_factor: options.fill_factor,
+        pad_index: options.pad_index,
+        data_compression: options.data_compression,
+        low_priority: low_priority_wait(options),
+    };
+    match index.index_kind {
+        IndexKind::Rowstore => {}
+        IndexKind::Heap => {
+            rebuild.fill_factor = 0;
+            rebuild.pad_index = false;
+        }
+        IndexKind::Xml | IndexKind::Spatial => {
+            rebuild.data_compression = DataCompression::Keep;
+        }
+        IndexKind::ClusteredColumnstore | IndexKind::NonclusteredColumnstore => {
+            rebuild.sort_in_tempdb = false;
+            rebuild.fill_factor = 0;
+            rebuild.pad_index = false;
+            rebuild.data_compression = DataCompression::Keep;
+        }
+    }
+    rebuild
+}
+
+/// Builds the ALTER INDEX statement for `action`, or `None` when the index is skipped.
+/// Shared by the live run and the dry-run plan so both always agree on the SQL.
+fn index_operation_sql(
+    index: &IndexInfo,
+    decision: &IndexDecision,
+    options: &MaintenanceOptions,
+) -> Option<String> {
+    match decision.action {
+        MaintenanceAction::Rebuild if index.paused_rebuild_percent.is_some() => {
+            Some(resume_index_sql(
+                &index.schema_name,
+                &index.table_name,
+                &index.index_name,
+                options.resumable_max_duration_minutes,
+                low_priority_wait(options).as_ref(),
+            ))
+        }
+        MaintenanceAction::Rebuild => Some(rebuild_index_sql(
+            &index.schema_name,
+            &index.table_name,
+            &index.index_name,
+            index.target_partition(),
+            &rebuild_options(index, decision, options),
+        )),
+        MaintenanceAction::Reorganize if index.index_kind.is_columnstore() => {
+            Some(reorganize_columnstore_sql(
+                &index.schema_name,
+                &index.table_name,
+                &index.index_name,
+                index.target_partition(),
+            ))
+        }
+        MaintenanceAction::Reorganize => Some(reorganize_index_sql(
+            &index.schema_name,
+            &index.table_name,
+            &index.index_name,
+            index.target_partition(),
+        )),
+        MaintenanceAction::RebuildHeap => Some(rebuild_heap_sql(
+            &index.schema_name,
+            &index.table_name,
+            index.target_partition(),
+            &rebuild_options(index, decision, options),
+        )),
+        MaintenanceAction::Skip => None,
+    }
+}
+
+/// `schema.table.index`, plus the partition for partition-level operations.
+fn index_label(index: &IndexInfo) -> String {
+    if index.index_kind == IndexKind::Heap {
+        return match index.target_partition() {
+            Some(n) => format!("{}.{} (heap, partition {})", index.schema_name, index.table_name, n),
+            None => format!("{}.{} (heap)", index.schema_name, index.table_name),
+        };
+    }
+    match index.target_partition() {
+        Some(n) => format!(
+            "{}.{}.{} (partition {})",
+            index.schema_name, index.table_name, index.index_name, n
+        ),
+        None => format!("{}.{}.{}", index.schema_name, index.table_name, index.index_name),
+    }
+}
+
+/// Identifies an index's statistics object regardless of partition.
+fn stats_key(index: &IndexInfo) -> String {
+    statistics_key(&index.schema_name, &index.table_name, &index.index_name)
+}
+
+/// Key of a statistics object; an index's statistics share the index's name.
+fn statistics_key(schema: &str, table: &str, stats_name: &str) -> String {
+    format!("{}.{}.{}", schema, table, stats_name)
+}
+
+/// Whether `action` leaves the index's statistics freshly updated. Rebuilding a
+/// whole rowstore index refreshes them with the equivalent of a full scan;
+/// partition rebuilds, reorganizes and heap rebuilds do not.
+fn refreshes_statistics(index: &IndexInfo, action: &MaintenanceAction) -> bool {
+    *action == MaintenanceAction::Rebuild
+        && index.index_kind == IndexKind::Rowstore
+        && index.target_partition().is_none()
+}
+
+/// `UPDATE STATISTICS ... WITH FULLSCAN` run right after `action` when the
+/// statistics pass is off, so every maintained index ends up with statistics as
+/// fresh as a full rebuild leaves them. `None` when the action already refreshed
+/// them, and for XML and spatial indexes, which have no statistics of their own.
+/// Heaps have no index statistics either; their table's statistics are updated.
+fn post_operation_statistics_sql(
+    index: &IndexInfo,
+    action: &MaintenanceAction,
+    options: &MaintenanceOptions,
+) -> Option<String> {
+    if options.update_statistics
+        || *action == MaintenanceAction::Skip
+        || refreshes_statistics(index, action)
+    {
+        return None;
+    }
+    match index.index_kind {
+        IndexKind::Heap => Some(update_table_statistics_sql(
+            &index.schema_name,
+            &index.table_name,
+            StatisticsSampling::Fullscan,
+            100,
+        )),
+        IndexKind::Xml | IndexKind::Spatial => None,
+        _ => Some(update_statistics_sql(
+            &index.schema_name,
+            &index.table_name,
+            &index.index_name,
+            StatisticsSampling::Fullscan,
+            100,
+        )),
+    }
+}
+
+/// "Lock request time out period exceeded" — raised when the session's
+/// `LOCK_TIMEOUT` or a `WAIT_AT_LOW_PRIORITY ... ABORT_AFTER_WAIT = SELF` wait
+/// runs out.
+const LOCK_TIMEOUT_ERROR: u32 = 1222;
+
+/// "The operation elapsed time exceeded the maximum time specified for this
+/// operation" — a resumable rebuild reached `MAX_DURATION` and paused itself.
+const MAX_DURATION_ERROR: u32 = 3643;
+
+// ---------------------------------------------------------------------------
+// Statistics thresholds
+// ---------------------------------------------------------------------------
+
+/// Why a statistic is due for an update, or `None` to leave it alone.
+/// Statistics without modifications since their last update are never due.
+fn statistics_update_reason(stat: &StatisticsInfo, options: &MaintenanceOptions) -> Option<String> {
+    let modifications = stat.modification_counter.unwrap_or(0);
+    if modifications <= 0 {
+        return None;
+    }
+    if options.statistics_modification_threshold > 0.0 {
+        // A statistic built on an empty table is stale as soon as rows arrive.
+        let modified_percent = match stat.rows {
+            Some(rows) if rows > 0 => modifications as f64 * 100.0 / rows as f64,
+            _ => 100.0,
+        };
+        if modified_percent >= options.statistics_modification_threshold {
+            return Some(format!("{:.1}% of rows modified since last update", modified_percent));
+        }
+    }
+    if options.statistics_max_age_days > 0 {
+        if let Some(days) = stat.days_since_update {
+            if days >= options.statistics_max_age_days as i32 {
+                return Some(format!("Last updated {} days ago", days));
+            }
+        }
+    }
+    None
+}
+
+fn statistics_sql(stat: &StatisticsInfo, options: &MaintenanceOptions) -> String {
+    update_statistics_sql(
+        &stat.schema_name,
+        &stat.table_name,
+        &stat.stats_name,
+        options.statistics_sampling,
+        options.statistics_sample_percent,
+    )
+}
+
+/// Statistics due for an update with the reason for each, leaving out index
+/// statistics in `refreshed` (keyed like [`stats_key`]) that a rebuild already updated.
+async fn fetch_statistics_work(
+    client: &mut tiberius::Client<tokio_util::compat::Compat<tokio::net::TcpStream>>,
+    options: &MaintenanceOptions,
+    refreshed: &HashSet<String>,
+) -> Result<Vec<(StatisticsInfo, String)>, SqlError> {
+    let stats = fetch_statistics(client, options.include_column_statistics).await?;
+    Ok(stats
+        .into_iter()
+        .filter(|stat| {
+            !(stat.is_index_statistics
+                && refreshed.contains(&statistics_key(
+                    &stat.schema_name,
+                    &stat.table_name,
+                    &stat.stats_name,
+                )))
+        })
+        .filter(|stat| statistics_rejection(options, stat).is_none())
+        .filter_map(|stat| statistics_update_reason(&stat, options).map(|reason| (stat, reason)))
+        .collect())
+}
+
+// ---------------------------------------------------------------------------
+// Maintenance window
+// ---------------------------------------------------------------------------
+
+/// Parses a `HH:MM` window end time.
+fn parse_window_end(value: &str) -> Option<chrono::NaiveTime> {
+    chrono::NaiveTime::parse_from_str(value.trim(), "%H:%M").ok()
+}
+
+/// When a run started now has to be done: the next occurrence of the window end
+/// time or the maximum run duration, whichever comes first.
+fn run_deadline(options: &MaintenanceOptions) -> Option<Instant> {
+    let by_end_time = parse_window_end(&options.window_end_time).and_then(|end| {
+        let now = chrono::Local::now().naive_local();
+        let mut close = now.date().and_time(end);
+        if close <= now {
+            close += chrono::Duration::days(1);
+        }
+        (close - now).to_std().ok().map(|left| Instant::now() + left)
+    });
+    let by_duration = (options.max_run_minutes > 0)
+        .then(|| Instant::now() + Duration::from_secs(u64::from(options.max_run_minutes) * 60));
+    match (by_end_time, by_duration) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Why an index operation has to wait for the next window, or `None` when it
+/// may start. Operations with no duration on record only need the window open.
+fn deferral_reason(ctx: &MaintenanceCtx, index: &IndexInfo, action: &MaintenanceAction) -> Option<String> {
+    let deadline = ctx.deadline?;
+    let left = deadline.saturating_duration_since(Instant::now()).as_secs_f64();
+    window_deferral(left, operation_estimate(&ctx.duration_estimates, index, action))
+}
+
+fn operation_estimate(
+    estimates: &HashMap<String, f64>,
+    index: &IndexInfo,
+    action: &MaintenanceAction,
+) -> Option<f64> {
+    let key = duration_key(
+        &index.database_name,
+        &index.schema_name,
+        &index.table_name,
+        &index.index_name,
+        index.target_partition(),
+        action,
+    );
+    estimates.get(&key).copied()
+}
+
+/// Deferral rule shared by runs and plans: `left` seconds remain in the window
+/// and the operation last took `estimate` seconds.
+fn window_deferral(left: f64, estimate: Option<f64>) -> Option<String> {
+    if left <= 0.0 {
+        return Some("Deferred: the maintenance window has closed".to_string());
+    }
+    let estimate = estimate?;
+    (estimate > left).then(|| {
+        format!(
+            "Deferred: last run took {:.0}s, only {:.0}s left in the maintenance window",
+            estimate, left
+        )
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Operation guards — checked before each index operation
+// ---------------------------------------------------------------------------
+
+/// How often a holding guard re-reads what it is waiting on.
+const GUARD_POLL: Duration = Duration::from_secs(15);
+
+enum GuardOutcome {
+    Proceed,
+    Stopped,
+    ManuallySkipped,
+}
+
+/// Waits one poll interval of a holding guard, then out any pause. `Some` when
+/// the wait ends the hold: a stop, a manual skip, or the window closing (left
+/// to the deferral check).
+async fn guard_poll(ctx: &MaintenanceCtx, db_name: &str) -> Option<GuardOutcome> {
+    tokio::select! {
+        _ = sleep(GUARD_POLL) => {}
+        _ = wait_for_stop(&ctx.ctrl_rx) => return Some(GuardOutcome::Stopped),
+        _ = poll_skip_set(&ctx.skip_set, db_name) => return Some(GuardOutcome::ManuallySkipped),
+        _ = wait_for_deadline(ctx.deadline) => return Some(GuardOutcome::Proceed),
+    }
+    check_ctrl(&ctx.ctrl_rx).await.map(|_| GuardOutcome::Stopped)
+}
+
+/// Runs the guards in turn; the first that does not proceed decides.
+async fn guard_operation(
+    ctx: &MaintenanceCtx,
+    client: &mut tiberius::Client<tokio_util::compat::Compat<tokio::net::TcpStream>>,
+    db_name: &str,
+    options: &MaintenanceOptions,
+) -> GuardOutcome {
+    match guard_log_usage(ctx, client, db_name, options).await {
+        GuardOutcome::Proceed => guard_ag_queues(ctx, client, db_name, options).await,
+        outcome => outcome,
+    }
+}
+
+/// Holds off the next index operation while the transaction log of `db_name` is
+/// at least `log_usage_max_percent` full. Waits for a log backup to free space,
+/// or pauses the whole run through the control channel; resuming while the log
+/// is still too full pauses again. Unreadable log usage never blocks the run.
+async fn guard_log_usage(
+    ctx: &MaintenanceCtx,
+    client: &mut tiberius::Client<tokio_util::compat::Compat<tokio::net::TcpStream>>,
+    db_name: &str,
+    options: &MaintenanceOptions,
+) -> GuardOutcome {
+    if options.log_usage_max_percent <= 0.0 {
+        return GuardOutcome::Proceed;
+    }
+    let MaintenanceCtx { app, profile_id, ctrl_rx, .. } = ctx;
+    let mut held = false;
+    loop {
+        let Ok(usage) = fetch_log_space_usage(client).await else {
+            return GuardOutcome::Proceed;
+        };
+        let state = if usage.used_percent < options.log_usage_max_percent {
+            if !held {
+                return GuardOutcome::Proceed;
+            }
+            "cleared"
+        } else {
+            match options.log_usage_action {
+                LogUsageAction::Wait => "waiting",
+                LogUsageAction::Pause => "paused",
+            }
+        };
+        let _ = app.emit(
+            "maintenance:log-usage",
+            LogUsageEvent {
+                profile_id: profile_id.to_string(),
+                db_name: db_name.to_string(),
+                state: state.to_string(),
+                usage,
+                max_percent: options.log_usage_max_percent,
+            },
+        );
+        if state == "cleared" {
+            return GuardOutcome::Proceed;
+        }
+        held = true;
+
+        match options.log_usage_action {
+            LogUsageAction::Wait => {
+                if let Some(outcome) = guard_poll(ctx, db_name).await {
+                    return outcome;
+                }
+            }
+            LogUsageAction::Pause => {
+                if let Some(ctrl) = ctx.control_txs.lock().await.get(profile_id.as_ref()) {
+                    let _ = ctrl.tx.send(MaintenanceControl::Paused);
+                }
+                emit_control(app, profile_id, "paused");
+                if check_ctrl(ctrl_rx).await.is_some() {
+                    return GuardOutcome::Stopped;
+                }
+            }
+        }
+    }
+}
+
+/// Throttles the run while a secondary replica of `db_name` lags behind: holds
+/// off the next index operation until the log send and redo queues are back
+/// under their limits. Databases outside an Availability Group pass straight through.
+async fn guard_ag_queues(
+    ctx: &MaintenanceCtx,
+    client: &mut tiberius::Client<tokio_util::compat::Compat<tokio::net::TcpStream>>,
+    db_name: &str,
+    options: &MaintenanceOptions,
+) -> GuardOutcome {
+    let max_send = options.ag_max_log_send_queue_mb as f64;
+    let max_redo = options.ag_max_redo_queue_mb as f64;
+    if max_send <= 0.0 && max_redo <= 0.0 {
+        return GuardOutcome::Proceed;
+    }
+    let mut held = false;
+    loop {
+        let Ok(Some(queues)) = fetch_ag_queues(client).await else {
+            return GuardOutcome::Proceed;
+        };
+        let over = (max_send > 0.0 && queues.log_send_queue_mb >= max_send)
+            || (max_redo > 0.0 && queues.redo_queue_mb >= max_redo);
+        if !over && !held {
+            return GuardOutcome::Proceed;
+        }
+        let _ = ctx.app.emit(
+            "maintenance:ag-throttle",
+            AgThrottleEvent {
+                profile_id: ctx.profile_id.to_string(),
+                db_name: db_name.to_string(),
+                state: if over { "waiting" } else { "cleared" }.to_string(),
+                queues,
+                max_log_send_queue_mb: options.ag_max_log_send_queue_mb,
+                max_redo_queue_mb: options.ag_max_redo_queue_mb,
+            },
+        );
+        if !over {
+            return GuardOutcome::Proceed;
+        }
+        held = true;
+        if let Some(outcome) = guard_poll(ctx, db_name).await {
+            return outcome;
+        }
+    }
+}
+
+/// Reads the Availability Group role of every database through `master`. A
+/// failure is logged and leaves every database treated as outside a group.
+async fn load_replica_roles(
+    profile: &ServerProfile,
+    options: &MaintenanceOptions,
+) -> HashMap<String, (ReplicaRole, String)> {
+    let roles = match create_maintenance_client(profile, Some("master"), options).await {
+        Ok(mut client) => fetch_replica_roles(&mut client).await,
+        Err(e) => Err(e),
+    };
+    roles.unwrap_or_else(|e| {
+        eprintln!("Failed to read Availability Group roles: {e}");
+        HashMap::new()
+    })
+}
+
+/// Why a database is left alone because of its Availability Group role.
+fn replica_skip_reason(roles: &HashMap<String, (ReplicaRole, String)>, db_name: &str) -> Option<String> {
+    match roles.get(db_name)? {
+        (ReplicaRole::Secondary, group) => Some(format!(
+            "Secondary replica in availability group {}; maintain it on the primary",
+            group
+        )),
+        (ReplicaRole::Resolving, group) => {
+            Some(format!("Availability group {} is resolving its primary replica", group))
+        }
+        _ => None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Operation watch — progress and blocking of a running index operation, read
+// from a side connection
+// ---------------------------------------------------------------------------
+
+const WATCH_POLL: Duration = Duration::from_secs(5);
+
+fn watches_blocking(options: &MaintenanceOptions) -> bool {
+    options.max_blocking_secs > 0 || options.max_blocked_secs > 0
+}
+
+/// Wait type and origin of the other session, skipping what the server left blank.
+fn describe_session(session: &BlockingSession) -> String {
+    [
+        ("", &session.wait_type),
+        ("login ", &session.login_name),
+        ("host ", &session.host_name),
+        ("program ", &session.program_name),
+    ]
+    .iter()
+    .filter(|(_, value)| !value.is_empty())
+    .map(|(label, value)| format!("{}{}", label, value))
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+/// Why the operation should be cancelled, or `None` while the blocking is
+/// within the configured limits.
+fn blocking_abort_reason(snapshot: &BlockingSnapshot, options: &MaintenanceOptions) -> Option<String> {
+    let over = |limit: u32, session: &BlockingSession| limit > 0 && session.wait_secs >= limit as f64;
+    if let Some(s) = snapshot.blocking.as_ref().filter(|s| over(options.max_blocking_secs, s)) {
+        return Some(format!(
+            "aborted: blocking — session {} waited {:.0}s on this operation ({})",
+            s.session_id,
+            s.wait_secs,
+            describe_session(s)
+        ));
+    }
+    snapshot.blocked_by.as_ref().filter(|s| over(options.max_blocked_secs, s)).map(|s| {
+        format!(
+            "aborted: blocking — blocked for {:.0}s by session {} ({})",
+            s.wait_secs,
+            s.session_id,
+            describe_session(s)
+        )
+    })
+}
+
+/// Polls the request of `session_id` running `index`: emits its progress, and
+/// resolves with the session and the reason once a blocking limit is crossed.
+/// The side connection is only opened once the operation outlasts one poll.
+/// Never resolves without a session id, or when the side connection cannot read
+/// the DMVs (other sessions need VIEW SERVER STATE).
+async fn watch_operation(
+    ctx: &MaintenanceCtx,
+    index: Option<&IndexInfo>,
+    session_id: Option<i32>,
+) -> (i32, String) {
+    let (Some(index), Some(session_id)) = (index, session_id) else {
+        return std::future::pending().await;
+    };
+    sleep(WATCH_POLL).await;
+    let Ok(mut side) = create_maintenance_client(&ctx.profile, Some("master"), &ctx.options).await
+    else {
+        return std::future::pending().await;
+    };
+    loop {
+        let Ok(progress) = fetch_request_progress(&mut side, session_id).await else {
+            return std::future::pending().await;
+        };
+        if let Some(progress) = progress {
+            let _ = ctx.app.emit(
+                "maintenance:index-progress",
+                IndexProgressEvent {
+                    profile_id: ctx.profile_id.to_string(),
+                    db_name: index.database_name.clone(),
+                    schema_name: index.schema_name.clone(),
+                    table_name: index.table_name.clone(),
+                    index_name: index.index_name.clone(),
+                    partition_number: index.target_partition(),
+                    progress,
+                },
+            );
+        }
+        if watches_blocking(&ctx.options) {
+            let Ok(snapshot) = fetch_blocking(&mut side, session_id).await else {
+                return std::future::pending().await;
+            };
+            if let Some(reason) = blocking_abort_reason(&snapshot, &ctx.options) {
+                return (session_id, reason);
+            }
+        }
+        sleep(WATCH_POLL).await;
+    }
+}
+
+/// Stops an operation the run gives up on, for the blocking monitor or a closed
+/// window: resumable rebuilds are paused so their progress survives, anything
+/// else is killed and rolls back. Either way the session's connection cannot be
+/// used for the next operation.
+async fn cancel_operation(
+    ctx: &MaintenanceCtx,
+    db_name: &str,
+    session_id: i32,
+    pause_sql: Option<&str>,
+) -> Result<(), SqlError> {
+    if let Some(pause_sql) = pause_sql {
+        return pause_from_side_connection(ctx, db_name, pause_sql).await;
+    }
+    let mut side = create_maintenance_client(&ctx.profile, Some("master"), &ctx.options).await?;
+    side.execute(format!("KILL {}", session_id), &[]).await?;
+    Ok(())
 }
 
 // ---------------------------------------------------------------------------
@@ -209,25 +1068,44 @@ fn is_transient_error(err: &str) -> bool {
 
 enum IndexOpResult {
     Success { attempts: u32, duration_secs: f64 },
-    Failure { attempts: u32, duration_secs: f64, error: String },
+    Failure { attempts: u32, duration_secs: f64, error: SqlError },
+    /// A resumable rebuild was paused server-side, by the run or by its own `MAX_DURATION`.
+    /// `timed_out` is set when the run paused it after the request timeout.
+    Paused { attempts: u32, duration_secs: f64, timed_out: bool },
+    /// The maintenance window closed while the operation ran. `paused` is set when
+    /// a resumable rebuild was paused server-side rather than abandoned.
+    DeadlineReached { attempts: u32, duration_secs: f64, paused: bool },
+    /// Cancelled by the blocking monitor; `detail` names the other session.
+    BlockingAborted { attempts: u32, duration_secs: f64, detail: String },
     Interrupted,
     ManuallySkipped,
 }
 
 /// Execute one ALTER INDEX with retry + pause/skip/stop interruptibility.
+/// With `pause_sql` (resumable rebuilds), pausing or stopping the run — or the
+/// window closing — pauses the rebuild server-side from a second connection
+/// instead of abandoning it. With `watch`, the operation on that index reports
+/// its progress and is cancelled once it blocks, or is blocked, past the
+/// configured limits. With `self_aborts`, a lock timeout is the operation's
+/// `ABORT_AFTER_WAIT = SELF` and is never retried.
 async fn execute_index_operation(
+    ctx: &MaintenanceCtx,
     client: &mut tiberius::Client<tokio_util::compat::Compat<tokio::net::TcpStream>>,
     sql: &str,
-    options: &MaintenanceOptions,
-    ctrl_rx: &watch::Receiver<MaintenanceControl>,
-    skip_set: &Arc<Mutex<HashSet<String>>>,
     db_name: &str,
+    pause_sql: Option<&str>,
+    watch: Option<&IndexInfo>,
+    self_aborts: bool,
 ) -> IndexOpResult {
+    let MaintenanceCtx { options, ctrl_rx, skip_set, .. } = ctx;
+    // Needed to watch the operation, and to kill it if the window closes.
+    let mut session_id = fetch_session_id(client).await.ok();
     let op_start = std::time::Instant::now();
-    let mut last_err = String::new();
+    let mut last_err = SqlError::Other(String::new());
     let mut attempt = 0u32;
     for att in 1..=options.retry_max_attempts {
         attempt = att;
+        let mut timeout_pause = None;
 
         let sql_fut = async {
             if options.request_timeout_ms == 0 {
@@ -243,12 +1121,57 @@ async fn execute_index_operation(
 
         let execute_result = tokio::select! {
             res = sql_fut => res,
-            _ = wait_for_stop(ctrl_rx) => {
+            _ = wait_for_stop(ctrl_rx), if pause_sql.is_none() => {
                 return IndexOpResult::Interrupted;
             }
+            _ = wait_for_pause_or_stop(ctrl_rx), if pause_sql.is_some() => {
+                let pause_sql = pause_sql.unwrap_or_default();
+                let duration_secs = op_start.elapsed().as_secs_f64();
+                return match pause_from_side_connection(ctx, db_name, pause_sql).await {
+                    Ok(()) => IndexOpResult::Paused {
+                        attempts: attempt,
+                        duration_secs,
+                        timed_out: false,
+                    },
+                    Err(_) if *ctrl_rx.borrow() == MaintenanceControl::Stop => {
+                        IndexOpResult::Interrupted
+                    }
+                    Err(e) => IndexOpResult::Failure {
+                        attempts: attempt,
+                        duration_secs,
+                        error: SqlError::Other(format!("Failed to pause resumable rebuild: {}", e)),
+                    },
+                };
+            }
             _ = poll_skip_set(skip_set, db_name) => {
                 return IndexOpResult::ManuallySkipped;
             }
+            _ = wait_for_deadline(ctx.deadline) => {
+                // Dropping the request is not enough: the server would keep running
+                // it past the window.
+                let cancelled = match (pause_sql, session_id) {
+                    (Some(pause_sql), _) => pause_from_side_connection(ctx, db_name, pause_sql).await,
+                    (None, Some(session_id)) => cancel_operation(ctx, db_name, session_id, None).await,
+                    (None, None) => Err(SqlError::Other("session id unknown".to_string())),
+                };
+                let paused = pause_sql.is_some() && cancelled.is_ok();
+                return IndexOpResult::DeadlineReached {
+                    attempts: attempt,
+                    duration_secs: op_start.elapsed().as_secs_f64(),
+                    paused,
+                };
+            }
+            (session_id, reason) = watch_operation(ctx, watch, session_id) => {
+                let duration_secs = op_start.elapsed().as_secs_f64();
+                let detail = match cancel_operation(ctx, db_name, session_id, pause_sql).await {
+                    Ok(()) if pause_sql.is_some() => {
+                        format!("{}; paused server-side, the next run resumes it", reason)
+                    }
+                    Ok(()) => reason,
+                    Err(e) => format!("{}; cancelling it failed: {}", reason, e),
+                };
+                return IndexOpResult::BlockingAborted { attempts: attempt, duration_secs, detail };
+            }
         };
 
         match execute_result {
@@ -258,16 +1181,64 @@ async fn execute_index_operation(
                     duration_secs: op_start.elapsed().as_secs_f64(),
                 };
             }
-            Ok(Err(e)) => {
-                last_err = e.to_string();
-            }
+            Ok(Err(e)) => last_err = e.into(),
             Err(_elapsed) => {
-                last_err =
-                    format!("SQL request timed out after {}ms", options.request_timeout_ms);
+                last_err = SqlError::Timeout { timeout_ms: options.request_timeout_ms };
+                // The server keeps running a timed-out request; stop it so neither a
+                // retry nor the next operation queues behind it.
+                match (pause_sql, session_id) {
+                    (Some(pause_sql), _) => {
+                        timeout_pause =
+                            Some(pause_from_side_connection(ctx, db_name, pause_sql).await);
+                    }
+                    (None, Some(session_id)) => {
+                        let _ = cancel_operation(ctx, db_name, session_id, None).await;
+                    }
+                    (None, None) => {}
+                }
+            }
+        }
+
+        if last_err.needs_new_connection() {
+            match create_maintenance_client(&ctx.profile, Some(db_name), options).await {
+                Ok(c) => {
+                    *client = c;
+                    session_id = fetch_session_id(client).await.ok();
+                }
+                Err(e) => {
+                    last_err = SqlError::Other(format!("{}; reconnecting failed: {}", last_err, e));
+                    break;
+                }
             }
         }
 
-        if att == options.retry_max_attempts || !is_transient_error(&last_err) {
+        // A timed-out resumable rebuild is not retried; once paused, the next run
+        // resumes it.
+        if let Some(paused) = timeout_pause {
+            let duration_secs = op_start.elapsed().as_secs_f64();
+            return match paused {
+                Ok(()) => IndexOpResult::Paused { attempts: attempt, duration_secs, timed_out: true },
+                Err(e) => IndexOpResult::Failure {
+                    attempts: attempt,
+                    duration_secs,
+                    error: SqlError::Other(format!(
+                        "{}; pausing the resumable rebuild failed: {}",
+                        last_err, e
+                    )),
+                },
+            };
+        }
+
+        // A self-abort already waited out MAX_DURATION; retrying would wait again.
+        let self_aborted = self_aborts && last_err.number() == Some(LOCK_TIMEOUT_ERROR);
+        if att == options.retry_max_attempts
+            || self_aborted
+            || !last_err.is_retryable(
+                &options.retryable_error_numbers,
+                options.retry_timeouts,
+                options.retry_connection_errors,
+            )
+        {
             break;
         }
 
@@ -286,17 +1257,107 @@ async fn execute_index_operation(
     }
 }
 
+async fn pause_from_side_connection(
+    ctx: &MaintenanceCtx,
+    db_name: &str,
+    pause_sql: &str,
+) -> Result<(), SqlError> {
+    let mut side = create_maintenance_client(&ctx.profile, Some(db_name), &ctx.options).await?;
+    side.execute(pause_sql, &[]).await?;
+    Ok(())
+}
+
+/// Indexes to work through in a database: paused resumable rebuilds first, then
+/// the physical-stats results minus the indexes whose rebuild is being resumed,
+/// in the order of `options.index_priority`.
+async fn fetch_index_work(
+    client: &mut tiberius::Client<tokio_util::compat::Compat<tokio::net::TcpStream>>,
+    db_name: &str,
+    options: &MaintenanceOptions,
+    server: &ServerInfo,
+    last_maintained: &HashMap<String, String>,
+) -> Result<Vec<IndexInfo>, SqlError> {
+    let mut work = if server.at_least(14) {
+        fetch_paused_resumable_operations(client, db_name).await?
+    } else {
+        Vec::new()
+    };
+    apply_index_policy(&mut work, options);
+    let resumed: HashSet<String> = work.iter().map(stats_key).collect();
+    let mut indexes: Vec<IndexInfo> = fetch_fragmented_indexes(client, db_name, options)
+        .await?
+        .into_iter()
+        .filter(|i| !resumed.contains(&stats_key(i)))
+        .collect();
+
+    let usage = if options.index_priority == IndexPriority::MostUsed {
+        // The usage DMV needs VIEW SERVER STATE; without it every index counts as
+        // unused and the order falls back to fragmentation.
+        fetch_index_usage(client).await.unwrap_or_default()
+    } else {
+        HashMap::new()
+    };
+    prioritize_indexes(&mut indexes, options.index_priority, &usage, last_maintained);
+
+    work.extend(indexes);
+    Ok(work)
+}
+
+/// Sorts `indexes` by `priority`; ties keep the most fragmented first.
+fn prioritize_indexes(
+    indexes: &mut [IndexInfo],
+    priority: IndexPriority,
+    usage: &HashMap<String, i64>,
+    last_maintained: &HashMap<String, String>,
+) {
+    let by_fragmentation =
+        |a: &IndexInfo, b: &IndexInfo| b.fragmentation_percent.total_cmp(&a.fragmentation_percent);
+    match priority {
+        IndexPriority::Fragmentation => indexes.sort_by(by_fragmentation),
+        IndexPriority::LargestBenefit => indexes.sort_by(|a, b| {
+            let benefit = |i: &IndexInfo| i.fragmentation_percent * i.page_count as f64;
+            benefit(b).total_cmp(&benefit(a)).then_with(|| by_fragmentation(a, b))
+        }),
+        IndexPriority::MostUsed => indexes.sort_by(|a, b| {
+            let reads = |i: &IndexInfo| usage.get(&stats_key(i)).copied().unwrap_or(0);
+            reads(b).cmp(&reads(a)).then_with(|| by_fragmentation(a, b))
+        }),
+        IndexPriority::SmallestFirst => indexes.sort_by(|a, b| {
+            a.page_count.cmp(&b.page_count).then_with(|| by_fragmentation(a, b))
+        }),
+        IndexPriority::LeastRecentlyMaintained => indexes.sort_by(|a, b| {
+            // RFC 3339 UTC timestamps order lexically; `None` (never maintained) sorts first.
+            let maintained = |i: &IndexInfo| {
+                last_maintained.get(&index_key(
+                    &i.database_name,
+                    &i.schema_name,
+                    &i.table_name,
+                    &i.index_name,
+                    i.target_partition(),
+                ))
+            };
+            maintained(a).cmp(&maintained(b)).then_with(|| by_fragmentation(a, b))
+        }),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Summary builder
 // ---------------------------------------------------------------------------
 
-fn build_summary(results: Vec<DatabaseResult>, total_secs: f64) -> MaintenanceSummary {
+fn build_summary(
+    results: Vec<DatabaseResult>,
+    total_secs: f64,
+    options: &MaintenanceOptions,
+) -> MaintenanceSummary {
     let databases_processed = results.len() as u32;
     let databases_failed = results.iter().filter(|r| r.critical_failure).count() as u32;
-    let databases_skipped = results.iter().filter(|r| r.manually_skipped).count() as u32;
+    let databases_skipped =
+        results.iter().filter(|r| r.manually_skipped || r.skip_reason.is_some()).count() as u32;
     let total_indexes_rebuilt = results.iter().map(|r| r.indexes_rebuilt).sum();
     let total_indexes_reorganized = results.iter().map(|r| r.indexes_reorganized).sum();
     let total_indexes_skipped = results.iter().map(|r| r.indexes_skipped).sum();
+    let total_indexes_deferred = results.iter().map(|r| r.indexes_deferred).sum();
 
     MaintenanceSummary {
         databases_processed,
@@ -305,7 +1366,10 @@ fn build_summary(results: Vec<DatabaseResult>, total_secs: f64) -> MaintenanceSu
         total_indexes_rebuilt,
         total_indexes_reorganized,
         total_indexes_skipped,
+        total_indexes_deferred,
         total_duration_secs: total_secs,
+        min_page_count: options.min_page_count,
+        max_page_count: options.max_page_count,
         database_results: results,
     }
 }
@@ -318,12 +1382,16 @@ fn make_skipped_result(db_name: &str) -> DatabaseResult {
         indexes_rebuilt: 0,
         indexes_reorganized: 0,
         indexes_skipped: 0,
+        indexes_deferred: 0,
         total_duration_secs: 0.0,
         errors: vec![],
         critical_failure: false,
         manually_skipped: true,
+        skip_reason: None,
         interrupted: false,
         index_results: vec![],
+        statistics_updated: 0,
+        statistics_results: vec![],
     }
 }
 
@@ -335,12 +1403,16 @@ fn make_interrupted_result(db_name: &str, duration_secs: f64) -> DatabaseResult
         indexes_rebuilt: 0,
         indexes_reorganized: 0,
         indexes_skipped: 0,
+        indexes_deferred: 0,
         total_duration_secs: duration_secs,
         errors: vec![],
         critical_failure: false,
         manually_skipped: false,
+        skip_reason: None,
         interrupted: true,
         index_results: vec![],
+        statistics_updated: 0,
+        statistics_results: vec![],
     }
 }
 
@@ -348,6 +1420,80 @@ fn make_interrupted_result(db_name: &str, duration_secs: f64) -> DatabaseResult
 // Tauri commands
 // ---------------------------------------------------------------------------
 
+fn validate_run_request(databases: &[String], options: &MaintenanceOptions) -> Result<(), String> {
+    if databases.is_empty() {
+        return Err("No databases selected".to_string());
+    }
+    if databases.iter().all(|db| database_rejection(&options.database_filter, db).is_some()) {
+        return Err("Every selected database is excluded by the database patterns".to_string());
+    }
+    if options.reorganize_threshold <= 0.0 || options.rebuild_threshold <= 0.0 {
+        return Err("Fragmentation thresholds must be positive".to_string());
+    }
+    if !(0.0..=100.0).contains(&options.page_density_threshold) {
+        return Err("Page density threshold must be between 0 and 100".to_string());
+    }
+    if options.columnstore_reorganize_threshold <= 0.0 || options.columnstore_rebuild_threshold <= 0.0
+    {
+        return Err("Columnstore deleted-row thresholds must be positive".to_string());
+    }
+    if options.columnstore_delta_rowgroup_threshold < 0 {
+        return Err("Columnstore delta rowgroup threshold cannot be negative".to_string());
+    }
+    if !(0.0..=100.0).contains(&options.heap_forwarded_record_threshold)
+        || !(0.0..=100.0).contains(&options.heap_fragmentation_threshold)
+    {
+        return Err("Heap thresholds must be between 0 and 100".to_string());
+    }
+    if options.min_page_count < 0 || options.max_page_count < 0 {
+        return Err("Page count limits cannot be negative".to_string());
+    }
+    if options.max_page_count > 0 && options.max_page_count < options.min_page_count {
+        return Err("Maximum page count must be at least the minimum page count".to_string());
+    }
+    if options.application_name.trim().chars().count() > 128 {
+        return Err("Application name cannot exceed 128 characters".to_string());
+    }
+    if options.low_priority_wait && options.low_priority_max_duration_minutes == 0 {
+        return Err("Low priority wait duration must be at least 1 minute".to_string());
+    }
+    if options.statistics_modification_threshold < 0.0 {
+        return Err("Statistics modification threshold cannot be negative".to_string());
+    }
+    if options.update_statistics && !(1..=100).contains(&options.statistics_sample_percent) {
+        return Err("Statistics sample percent must be between 1 and 100".to_string());
+    }
+    if !options.window_end_time.trim().is_empty() && parse_window_end(&options.window_end_time).is_none() {
+        return Err("Maintenance window end time must be HH:MM".to_string());
+    }
+    if options.fill_factor > 100 {
+        return Err("Fill factor must be between 0 and 100".to_string());
+    }
+    if !(0.0..=100.0).contains(&options.log_usage_max_percent) {
+        return Err("Log usage limit must be between 0 and 100".to_string());
+    }
+    for (n, o) in options.overrides.iter().enumerate() {
+        if o.database.trim().is_empty() {
+            return Err(format!("Override {} needs a database pattern", n + 1));
+        }
+        if o.rebuild_threshold.is_some_and(|v| v <= 0.0)
+            || o.reorganize_threshold.is_some_and(|v| v <= 0.0)
+        {
+            return Err(format!("Override {}: fragmentation thresholds must be positive", n + 1));
+        }
+        if o.fill_factor.is_some_and(|v| v > 100) {
+            return Err(format!("Override {}: fill factor must be between 0 and 100", n + 1));
+        }
+    }
+    if options.retry_max_attempts == 0 {
+        return Err("Retry max attempts must be at least 1".to_string());
+    }
+    if options.parallel_databases && options.max_parallel_databases == 0 {
+        return Err("Max parallel databases must be at least 1".to_string());
+    }
+    Ok(())
+}
+
 /// Guard that removes the profile's control channel entry even if the task panics.
 struct ControlGuard {
     control_txs: Arc<Mutex<HashMap<String, ProfileControl>>>,
@@ -381,23 +1527,12 @@ pub async fn run_maintenance(
     app: AppHandle,
     state: State<'_, AppState>,
     profile_id: String,
-    databases: Vec<String>,
+    mut databases: Vec<String>,
     options: MaintenanceOptions,
 ) -> Result<(), String> {
-    if databases.is_empty() {
-        return Err("No databases selected".to_string());
-    }
-
     // Validate thresholds before spawning the task
-    if options.reorganize_threshold <= 0.0 || options.rebuild_threshold <= 0.0 {
-        return Err("Fragmentation thresholds must be positive".to_string());
-    }
-    if options.retry_max_attempts == 0 {
-        return Err("Retry max attempts must be at least 1".to_string());
-    }
-    if options.parallel_databases && options.max_parallel_databases == 0 {
-        return Err("Max parallel databases must be at least 1".to_string());
-    }
+    validate_run_request(&databases, &options)?;
+    databases.retain(|db| database_rejection(&options.database_filter, db).is_none());
 
     // Load full credentials server-side — passwords never travel over IPC
     let profile = load_full_profile(&app, &state.profile_io_lock, &profile_id).await?;
@@ -422,6 +1557,18 @@ pub async fn run_maintenance(
     let guard_txs = control_txs.clone();
     let guard_id = profile_id.to_string();
 
+    let deadline = run_deadline(&options);
+    let duration_estimates = if deadline.is_some() {
+        let id = profile_id.to_string();
+        read_history(&history_db, "index durations", move |conn| {
+            index_duration_estimates(conn, &id, HISTORY_RUNS)
+        })
+        .await
+    } else {
+        HashMap::new()
+    };
+    let last_maintained = load_last_maintained(&history_db, &profile_id, &options).await;
+
     let ctx = MaintenanceCtx {
         app,
         control_txs,
@@ -431,6 +1578,11 @@ pub async fn run_maintenance(
         profile,
         options,
         skip_set,
+        server: Arc::new(OnceCell::new()),
+        deadline,
+        duration_estimates: Arc::new(duration_estimates),
+        last_maintained: Arc::new(last_maintained),
+        replica_roles: Arc::new(OnceCell::new()),
     };
 
     tauri::async_runtime::spawn(async move {
@@ -443,6 +1595,85 @@ pub async fn run_maintenance(
     Ok(())
 }
 
+/// Dry run: analyses every selected database and returns the decisions and exact
+/// T-SQL a run with the same options would execute. Nothing is modified.
+#[specta::specta]
+#[tauri::command]
+pub async fn plan_maintenance(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    profile_id: String,
+    mut databases: Vec<String>,
+    options: MaintenanceOptions,
+) -> Result<MaintenancePlan, String> {
+    validate_run_request(&databases, &options)?;
+    databases.retain(|db| database_rejection(&options.database_filter, db).is_none());
+
+    let profile = load_full_profile(&app, &state.profile_io_lock, &profile_id).await?;
+    let generated_at = chrono::Utc::now().to_rfc3339();
+
+    let last_maintained = load_last_maintained(&state.history_db, &profile.id, &options).await;
+    // The window is drawn down by each planned operation's past duration, as a
+    // sequential run would spend it.
+    let mut window_left = run_deadline(&options)
+        .map(|deadline| deadline.saturating_duration_since(Instant::now()).as_secs_f64());
+    let duration_estimates = if window_left.is_some() {
+        let id = profile.id.clone();
+        read_history(&state.history_db, "index durations", move |conn| {
+            index_duration_estimates(conn, &id, HISTORY_RUNS)
+        })
+        .await
+    } else {
+        HashMap::new()
+    };
+    let server = OnceCell::new();
+    let roles = load_replica_roles(&profile, &options).await;
+    let mut database_plans = Vec::with_capacity(databases.len());
+    for db_name in &databases {
+        if let Some(reason) = replica_skip_reason(&roles, db_name) {
+            database_plans.push(DatabasePlan {
+                database_name: db_name.clone(),
+                indexes: vec![],
+                statistics: vec![],
+                error: Some(reason),
+            });
+            continue;
+        }
+        let window = PlanWindow { left: &mut window_left, estimates: &duration_estimates };
+        database_plans.push(
+            plan_database(&profile, db_name, &options, &server, &last_maintained, window).await,
+        );
+    }
+
+    Ok(MaintenancePlan {
+        profile_id: profile.id.clone(),
+        server: profile.server.clone(),
+        generated_at,
+        options,
+        database_plans,
+    })
+}
+
+/// Writes a previously generated plan as a standalone T-SQL script to the user's
+/// downloads folder and returns its path. The file name is built here, never
+/// taken from the frontend, so the command cannot write anywhere else.
+#[specta::specta]
+#[tauri::command]
+pub async fn export_maintenance_script(app: AppHandle, plan: MaintenancePlan) -> Result<String, String> {
+    let dir = app
+        .path()
+        .download_dir()
+        .or_else(|_| app.path().app_data_dir())
+        .map_err(|e| format!("No folder to export the script to: {}", e))?;
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
+    let path = dir.join(script_file_name(&plan.server, &timestamp));
+    let script = build_plan_script(&plan);
+    tokio::fs::write(&path, script)
+        .await
+        .map_err(|e| format!("Failed to write script to {}: {}", path.display(), e))?;
+    Ok(path.display().to_string())
+}
+
 #[specta::specta]
 #[tauri::command]
 pub async fn pause_maintenance(
@@ -535,7 +1766,7 @@ async fn maintenance_task(ctx: MaintenanceCtx, databases: Vec<String>) {
 // ---------------------------------------------------------------------------
 
 async fn finish_run(ctx: &MaintenanceCtx, results: Vec<DatabaseResult>, run_start: std::time::Instant, started_at: &str) {
-    let summary = build_summary(results, run_start.elapsed().as_secs_f64());
+    let summary = build_summary(results, run_start.elapsed().as_secs_f64(), &ctx.options);
 
     persist_history(
         &ctx.history_db,
@@ -555,6 +1786,141 @@ async fn finish_run(ctx: &MaintenanceCtx, results: Vec<DatabaseResult>, run_star
     );
 }
 
+// ---------------------------------------------------------------------------
+// Statistics pass
+// ---------------------------------------------------------------------------
+
+/// Updates the database's statistics that crossed a threshold and records each
+/// update in `result`. Returns (stopped, manually_skipped).
+async fn run_statistics_pass(
+    ctx: &MaintenanceCtx,
+    client: &mut tiberius::Client<tokio_util::compat::Compat<tokio::net::TcpStream>>,
+    db_name: &str,
+    refreshed: &HashSet<String>,
+    result: &mut DatabaseResult,
+) -> (bool, bool) {
+    let MaintenanceCtx { options, ctrl_rx, skip_set, .. } = ctx;
+
+    let work = tokio::select! {
+        res = fetch_statistics_work(client, options, refreshed) => {
+            match res {
+                Ok(work) => work,
+                Err(e) => {
+                    result.success = false;
+                    result.errors.push(format!("Failed to fetch statistics: {}", e));
+                    return (false, false);
+                }
+            }
+        }
+        _ = wait_for_stop(ctrl_rx) => return (true, false),
+        _ = poll_skip_set(skip_set, db_name) => return (false, true),
+    };
+
+    for (stat, reason) in work {
+        if check_ctrl(ctrl_rx).await == Some(MaintenanceControl::Stop) {
+            return (true, false);
+        }
+        if skip_set.lock().await.remove(db_name) {
+            return (false, true);
+        }
+        if window_closed(ctx.deadline) {
+            break;
+        }
+
+        let sql = statistics_sql(&stat, options);
+        let (duration_secs, retry_attempts, error) =
+            match execute_statistics_update(ctx, client, &sql, db_name).await {
+                StatisticsUpdate::Done { duration_secs, attempts, error } => {
+                    (duration_secs, attempts, error)
+                }
+                StatisticsUpdate::Interrupted => return (true, false),
+                StatisticsUpdate::ManuallySkipped => return (false, true),
+                StatisticsUpdate::DeadlineReached => break,
+            };
+
+        let stats_result = StatisticsResult {
+            schema_name: stat.schema_name,
+            table_name: stat.table_name,
+            stats_name: stat.stats_name,
+            is_index_statistics: stat.is_index_statistics,
+            rows: stat.rows,
+            modification_counter: stat.modification_counter,
+            last_updated: stat.last_updated,
+            sampling: options.statistics_sampling,
+            success: error.is_none(),
+            duration_secs,
+            retry_attempts,
+            error,
+            reason,
+        };
+        record_statistics_result(ctx, db_name, result, stats_result);
+    }
+
+    (false, false)
+}
+
+enum StatisticsUpdate {
+    Done { duration_secs: f64, attempts: u32, error: Option<String> },
+    Interrupted,
+    ManuallySkipped,
+    DeadlineReached,
+}
+
+/// Runs one UPDATE STATISTICS with the run's retry and interruption handling.
+async fn execute_statistics_update(
+    ctx: &MaintenanceCtx,
+    client: &mut tiberius::Client<tokio_util::compat::Compat<tokio::net::TcpStream>>,
+    sql: &str,
+    db_name: &str,
+) -> StatisticsUpdate {
+    let (duration_secs, attempts, error) =
+        match execute_index_operation(ctx, client, sql, db_name, None, None, false).await {
+            IndexOpResult::Success { attempts, duration_secs } => (duration_secs, attempts, None),
+            IndexOpResult::Failure { attempts, duration_secs, error } => {
+                (duration_secs, attempts, Some(error.to_string()))
+            }
+            // Neither is expected without a pause statement or a watched index;
+            // they are reported as failures rather than trusted to be impossible.
+            IndexOpResult::Paused { attempts, duration_secs, .. } => {
+                (duration_secs, attempts, Some("Statistics update was paused".to_string()))
+            }
+            IndexOpResult::BlockingAborted { attempts, duration_secs, detail } => {
+                (duration_secs, attempts, Some(detail))
+            }
+            IndexOpResult::Interrupted => return StatisticsUpdate::Interrupted,
+            IndexOpResult::ManuallySkipped => return StatisticsUpdate::ManuallySkipped,
+            IndexOpResult::DeadlineReached { .. } => return StatisticsUpdate::DeadlineReached,
+        };
+    StatisticsUpdate::Done { duration_secs, attempts, error }
+}
+
+fn record_statistics_result(
+    ctx: &MaintenanceCtx,
+    db_name: &str,
+    result: &mut DatabaseResult,
+    stats_result: StatisticsResult,
+) {
+    match &stats_result.error {
+        None => result.statistics_updated += 1,
+        Some(err) => {
+            result.success = false;
+            result.errors.push(format!(
+                "{}.{}.{} statistics: {}",
+                stats_result.schema_name, stats_result.table_name, stats_result.stats_name, err
+            ));
+        }
+    }
+    result.statistics_results.push(stats_result.clone());
+    let _ = ctx.app.emit(
+        "maintenance:statistics-complete",
+        StatisticsCompleteEvent {
+            profile_id: ctx.profile_id.to_string(),
+            db_name: db_name.to_string(),
+            result: stats_result,
+        },
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Sequential maintenance task
 // ---------------------------------------------------------------------------
@@ -574,6 +1940,10 @@ async fn maintenance_task_sequential(ctx: MaintenanceCtx, databases: Vec<String>
             }
             _ => {}
         }
+        // Databases not started before the window closes are left for the next run.
+        if window_closed(ctx.deadline) {
+            break 'outer;
+        }
 
         let _ = ctx.app.emit(
             "maintenance:db-start",
@@ -585,16 +1955,7 @@ async fn maintenance_task_sequential(ctx: MaintenanceCtx, databases: Vec<String>
             },
         );
 
-        let (result, should_stop) = process_database(
-            &ctx.app,
-            &ctx.profile_id,
-            &ctx.profile,
-            db_name,
-            &ctx.options,
-            &ctx.ctrl_rx,
-            &ctx.skip_set,
-        )
-        .await;
+        let (result, should_stop) = process_database(&ctx, db_name).await;
 
         let _ = ctx.app.emit(
             "maintenance:db-complete",
@@ -637,16 +1998,11 @@ async fn maintenance_task_parallel(ctx: MaintenanceCtx, databases: Vec<String>)
         let permit = semaphore.clone().acquire_owned().await.unwrap();
 
         // Re-check after potentially blocking on the semaphore while others ran.
-        if *ctx.ctrl_rx.borrow() == MaintenanceControl::Stop {
+        if *ctx.ctrl_rx.borrow() == MaintenanceControl::Stop || window_closed(ctx.deadline) {
             break; // permit drops here, releasing the slot
         }
 
-        let app_clone = ctx.app.clone();
-        let profile_clone = ctx.profile.clone();
-        let options_clone = ctx.options.clone();
-        let ctrl_rx_clone = ctx.ctrl_rx.clone();
-        let skip_set_clone = ctx.skip_set.clone();
-        let profile_id_clone = ctx.profile_id.clone();
+        let task_ctx = ctx.clone();
         let db_name_clone = db_name.clone();
         let results_clone = ordered_results.clone();
 
@@ -663,20 +2019,11 @@ async fn maintenance_task_parallel(ctx: MaintenanceCtx, databases: Vec<String>)
 
         join_set.spawn(async move {
             let _permit = permit;
-            let (result, should_stop) = process_database(
-                &app_clone,
-                &profile_id_clone,
-                &profile_clone,
-                &db_name_clone,
-                &options_clone,
-                &ctrl_rx_clone,
-                &skip_set_clone,
-            )
-            .await;
+            let (result, should_stop) = process_database(&task_ctx, &db_name_clone).await;
 
-            let _ = app_clone.emit(
+            let _ = task_ctx.app.emit(
                 "maintenance:db-complete",
-                DbCompleteEvent { profile_id: profile_id_clone.to_string(), result: result.clone() },
+                DbCompleteEvent { profile_id: task_ctx.profile_id.to_string(), result: result.clone() },
             );
 
             results_clone.lock().await.push((idx, result));
@@ -743,11 +2090,188 @@ async fn persist_history(
     })
     .await;
 
-    match result {
-        Ok(Err(e)) => eprintln!("Failed to persist run history: {e}"),
-        Err(e) => eprintln!("History persistence task panicked: {e}"),
-        _ => {}
+    match result {
+        Ok(Err(e)) => eprintln!("Failed to persist run history: {e}"),
+        Err(e) => eprintln!("History persistence task panicked: {e}"),
+        _ => {}
+    }
+}
+
+/// Reads from the history database off the async runtime. An unreadable history
+/// is logged and treated as empty: runs only use it to order or defer work.
+async fn read_history<T, F>(
+    history_db: &Arc<tokio::sync::Mutex<rusqlite::Connection>>,
+    what: &str,
+    read: F,
+) -> T
+where
+    T: Default + Send + 'static,
+    F: FnOnce(&rusqlite::Connection) -> rusqlite::Result<T> + Send + 'static,
+{
+    let db = history_db.clone();
+    let result = tokio::task::spawn_blocking(move || read(&db.blocking_lock())).await;
+
+    match result {
+        Ok(Ok(value)) => value,
+        Ok(Err(e)) => {
+            eprintln!("Failed to load {what} from history: {e}");
+            T::default()
+        }
+        Err(e) => {
+            eprintln!("History read task panicked: {e}");
+            T::default()
+        }
+    }
+}
+
+async fn load_last_maintained(
+    history_db: &Arc<tokio::sync::Mutex<rusqlite::Connection>>,
+    profile_id: &str,
+    options: &MaintenanceOptions,
+) -> HashMap<String, String> {
+    if options.index_priority != IndexPriority::LeastRecentlyMaintained {
+        return HashMap::new();
+    }
+    let id = profile_id.to_string();
+    read_history(history_db, "index maintenance times", move |conn| {
+        index_last_maintained(conn, &id, HISTORY_RUNS)
+    })
+    .await
+}
+
+/// How many past runs are searched for index durations and maintenance times.
+const HISTORY_RUNS: u32 = 20;
+
+// ---------------------------------------------------------------------------
+// Per-database planning (dry run)
+// ---------------------------------------------------------------------------
+
+/// Time left in the maintenance window while planning, and the past durations
+/// used to decide which operations it can still fit.
+struct PlanWindow<'a> {
+    /// Seconds left; `None` when the run has no window.
+    left: &'a mut Option<f64>,
+    estimates: &'a HashMap<String, f64>,
+}
+
+/// Plans one index the way `process_database` decides it: skipped indexes stay
+/// skipped, and operations the window can no longer fit are marked deferred.
+fn plan_index(
+    index: &IndexInfo,
+    options: &MaintenanceOptions,
+    server: &ServerInfo,
+    window: &mut PlanWindow<'_>,
+) -> IndexPlan {
+    let decision = decide_index(index, options, server);
+    let sql = index_operation_sql(index, &decision, options);
+    let IndexDecision { action, mut reason, .. } = decision;
+    let mut deferred = false;
+    if action != MaintenanceAction::Skip {
+        if let Some(left) = window.left.as_mut() {
+            let estimate = operation_estimate(window.estimates, index, &action);
+            match window_deferral(*left, estimate) {
+                Some(why) => {
+                    deferred = true;
+                    reason = Some(why);
+                }
+                None => *left -= estimate.unwrap_or(0.0),
+            }
+        }
+    }
+    IndexPlan {
+        schema_name: index.schema_name.clone(),
+        table_name: index.table_name.clone(),
+        index_name: index.index_name.clone(),
+        partition_number: index.target_partition(),
+        fragmentation_percent: index.fragmentation_percent,
+        page_count: index.page_count,
+        page_density_percent: index.page_density_percent,
+        statistics_sql: post_operation_statistics_sql(index, &action, options),
+        action,
+        sql,
+        reason,
+        deferred,
+    }
+}
+
+async fn plan_database(
+    profile: &ServerProfile,
+    db_name: &str,
+    options: &MaintenanceOptions,
+    server: &OnceCell<ServerInfo>,
+    last_maintained: &HashMap<String, String>,
+    mut window: PlanWindow<'_>,
+) -> DatabasePlan {
+    let mut plan = DatabasePlan {
+        database_name: db_name.to_string(),
+        indexes: vec![],
+        statistics: vec![],
+        error: None,
+    };
+
+    let mut client = match create_maintenance_client(profile, Some(db_name), options).await {
+        Ok(c) => c,
+        Err(e) => {
+            plan.error = Some(format!("Connection failed: {}", e));
+            return plan;
+        }
+    };
+
+    let server = match server.get_or_try_init(|| fetch_server_info(&mut client)).await {
+        Ok(info) => info,
+        Err(e) => {
+            plan.error = Some(format!("Failed to detect server edition: {}", e));
+            return plan;
+        }
+    };
+
+    let db_options = database_options(options, db_name);
+    let options: &MaintenanceOptions = &db_options;
+    let indexes =
+        match fetch_index_work(&mut client, db_name, options, server, last_maintained).await {
+            Ok(idxs) => idxs,
+            Err(e) => {
+                plan.error = Some(format!("Failed to fetch indexes: {}", e));
+                return plan;
+            }
+        };
+
+    let mut refreshed: HashSet<String> = HashSet::new();
+    plan.indexes = indexes
+        .iter()
+        .map(|index| {
+            let index_options =
+                table_options(options, db_name, &index.schema_name, &index.table_name);
+            let planned = plan_index(index, &index_options, server, &mut window);
+            if !planned.deferred && refreshes_statistics(index, &planned.action) {
+                refreshed.insert(stats_key(index));
+            }
+            planned
+        })
+        .collect();
+
+    if options.update_statistics {
+        match fetch_statistics_work(&mut client, options, &refreshed).await {
+            Ok(work) => {
+                plan.statistics = work
+                    .into_iter()
+                    .map(|(stat, reason)| StatisticsPlan {
+                        sql: statistics_sql(&stat, options),
+                        schema_name: stat.schema_name,
+                        table_name: stat.table_name,
+                        stats_name: stat.stats_name,
+                        rows: stat.rows,
+                        modification_counter: stat.modification_counter,
+                        last_updated: stat.last_updated,
+                        reason,
+                    })
+                    .collect();
+            }
+            Err(e) => plan.error = Some(format!("Failed to fetch statistics: {}", e)),
+        }
     }
+
+    plan
 }
 
 // ---------------------------------------------------------------------------
@@ -755,20 +2279,27 @@ async fn persist_history(
 // ---------------------------------------------------------------------------
 
 /// Returns (DatabaseResult, should_stop: bool)
-async fn process_database(
-    app: &AppHandle,
-    profile_id: &str,
-    profile: &ServerProfile,
-    db_name: &str,
-    options: &MaintenanceOptions,
-    ctrl_rx: &watch::Receiver<MaintenanceControl>,
-    skip_set: &Arc<Mutex<HashSet<String>>>,
-) -> (DatabaseResult, bool) {
+async fn process_database(ctx: &MaintenanceCtx, db_name: &str) -> (DatabaseResult, bool) {
+    let MaintenanceCtx { app, profile_id, profile, options, ctrl_rx, skip_set, .. } = ctx;
+    let db_options = database_options(options, db_name);
+    let options: &MaintenanceOptions = &db_options;
+
     // Check if this database was queued for skipping before it started.
     if skip_set.lock().await.remove(db_name) {
         return (make_skipped_result(db_name), false);
     }
 
+    // Secondaries are read-only, and connecting to a non-readable one fails.
+    let roles = ctx.replica_roles.get_or_init(|| load_replica_roles(profile, options)).await;
+    if let Some(reason) = replica_skip_reason(roles, db_name) {
+        let result = DatabaseResult {
+            manually_skipped: false,
+            skip_reason: Some(reason),
+            ..make_skipped_result(db_name)
+        };
+        return (result, false);
+    }
+
     let db_start = std::time::Instant::now();
     let mut result = DatabaseResult {
         database_name: db_name.to_string(),
@@ -777,16 +2308,20 @@ async fn process_database(
         indexes_rebuilt: 0,
         indexes_reorganized: 0,
         indexes_skipped: 0,
+        indexes_deferred: 0,
         total_duration_secs: 0.0,
         errors: vec![],
         critical_failure: false,
         manually_skipped: false,
+        skip_reason: None,
         interrupted: false,
         index_results: vec![],
+        statistics_updated: 0,
+        statistics_results: vec![],
     };
 
     let mut client = tokio::select! {
-        res = create_client(profile, Some(db_name), options.connection_timeout_ms) => {
+        res = create_maintenance_client(profile, Some(db_name), options) => {
             match res {
                 Ok(c) => c,
                 Err(e) => {
@@ -815,8 +2350,26 @@ async fn process_database(
         }
     };
 
+    let server = match ctx.server.get_or_try_init(|| fetch_server_info(&mut client)).await {
+        Ok(info) => info,
+        Err(e) => {
+            result.success = false;
+            result.critical_failure = true;
+            result.errors.push(format!("Failed to detect server edition: {}", e));
+            let _ = app.emit(
+                "maintenance:error",
+                MaintenanceErrorEvent {
+                    profile_id: profile_id.to_string(),
+                    message: format!("{}: {}", db_name, e),
+                },
+            );
+            result.total_duration_secs = db_start.elapsed().as_secs_f64();
+            return (result, false);
+        }
+    };
+
     let indexes = tokio::select! {
-        res = fetch_fragmented_indexes(&mut client, db_name) => {
+        res = fetch_index_work(&mut client, db_name, options, server, &ctx.last_maintained) => {
             match res {
                 Ok(idxs) => idxs,
                 Err(e) => {
@@ -854,6 +2407,8 @@ async fn process_database(
 
     let mut stopped = false;
     let mut manually_skipped = false;
+    // Index statistics already refreshed by a rebuild, left out of the statistics pass.
+    let mut refreshed: HashSet<String> = HashSet::new();
 
     // Check if skip was requested while we were connecting or fetching indexes
     // (covers the case where the DB has zero fragmented indexes and the loop never runs).
@@ -878,11 +2433,11 @@ async fn process_database(
         }
 
         result.indexes_processed += 1;
-        let action = determine_action(
-            index.fragmentation_percent,
-            options.reorganize_threshold,
-            options.rebuild_threshold,
-        );
+        let index_options = table_options(options, db_name, &index.schema_name, &index.table_name);
+        let options: &MaintenanceOptions = &index_options;
+        let decision = decide_index(index, options, server);
+        let sql = index_operation_sql(index, &decision, options);
+        let IndexDecision { action, online, resumable, reason } = decision;
 
         let _ = app.emit(
             "maintenance:index-action",
@@ -892,23 +2447,34 @@ async fn process_database(
                 schema_name: index.schema_name.clone(),
                 table_name: index.table_name.clone(),
                 index_name: index.index_name.clone(),
+                partition_number: index.target_partition(),
                 action: action.clone(),
             },
         );
 
         if action == MaintenanceAction::Skip {
+            let skip_outcome = if index.excluded_by.is_some() {
+                IndexOutcome::SkippedByPolicy
+            } else {
+                IndexOutcome::Skipped
+            };
             result.indexes_skipped += 1;
             result.index_results.push(IndexResult {
                 schema_name: index.schema_name.clone(),
                 table_name: index.table_name.clone(),
                 index_name: index.index_name.clone(),
+                partition_number: index.target_partition(),
                 fragmentation_percent: index.fragmentation_percent,
                 page_count: index.page_count,
+                forwarded_record_count: index.forwarded_record_count,
                 action: action.clone(),
                 success: true,
+                outcome: skip_outcome,
                 duration_secs: 0.0,
                 retry_attempts: 0,
                 error: None,
+                error_number: None,
+                reason: reason.clone(),
             });
             let _ = app.emit(
                 "maintenance:index-complete",
@@ -918,33 +2484,139 @@ async fn process_database(
                     schema_name: index.schema_name.clone(),
                     table_name: index.table_name.clone(),
                     index_name: index.index_name.clone(),
+                    partition_number: index.target_partition(),
                     action,
                     success: true,
+                    outcome: skip_outcome,
                     duration_secs: 0.0,
                     retry_attempts: 0,
                     error: None,
+                    reason: reason.clone(),
                 },
             );
             continue 'indexes;
         }
 
-        let sql = match action {
-            MaintenanceAction::Rebuild => rebuild_index_sql(
-                &index.schema_name,
-                &index.table_name,
-                &index.index_name,
-                options.rebuild_online,
-            ),
-            MaintenanceAction::Reorganize => reorganize_index_sql(
+        // Checked before the guards query the connection: once the window has
+        // closed, the connection may still carry a cancelled operation.
+        let mut deferral = deferral_reason(ctx, index, &action);
+        if deferral.is_none() {
+            match guard_operation(ctx, &mut client, db_name, options).await {
+                GuardOutcome::Proceed => {}
+                GuardOutcome::Stopped => {
+                    stopped = true;
+                    break 'indexes;
+                }
+                GuardOutcome::ManuallySkipped => {
+                    manually_skipped = true;
+                    break 'indexes;
+                }
+            }
+            // A guard may have held the operation until the window closed.
+            deferral = deferral_reason(ctx, index, &action);
+        }
+
+        if let Some(deferred) = deferral {
+            result.indexes_deferred += 1;
+            result.index_results.push(IndexResult {
+                schema_name: index.schema_name.clone(),
+                table_name: index.table_name.clone(),
+                index_name: index.index_name.clone(),
+                partition_number: index.target_partition(),
+                fragmentation_percent: index.fragmentation_percent,
+                page_count: index.page_count,
+                forwarded_record_count: index.forwarded_record_count,
+                action: action.clone(),
+                success: true,
+                outcome: IndexOutcome::Deferred,
+                duration_secs: 0.0,
+                retry_attempts: 0,
+                error: None,
+                error_number: None,
+                reason: Some(deferred.clone()),
+            });
+            let _ = app.emit(
+                "maintenance:index-complete",
+                IndexCompleteEvent {
+                    profile_id: profile_id.to_string(),
+                    db_name: index.database_name.clone(),
+                    schema_name: index.schema_name.clone(),
+                    table_name: index.table_name.clone(),
+                    index_name: index.index_name.clone(),
+                    partition_number: index.target_partition(),
+                    action,
+                    success: true,
+                    outcome: IndexOutcome::Deferred,
+                    duration_secs: 0.0,
+                    retry_attempts: 0,
+                    error: None,
+                    reason: Some(deferred),
+                },
+            );
+            continue 'indexes;
+        }
+
+        let sql = sql.expect("non-skip actions always produce SQL");
+        let pause_sql = resumable
+            .then(|| pause_index_sql(&index.schema_name, &index.table_name, &index.index_name));
+
+        let mut op_sql = sql;
+        let mut paused_by_stop = false;
+        let op_result = loop {
+            let res = execute_index_operation(
+                ctx,
+                &mut client,
+                &op_sql,
+                db_name,
+                pause_sql.as_deref(),
+                Some(index),
+                aborts_itself_at_low_priority(online, options),
+            )
+            .await;
+            let (attempts, duration_secs) = match res {
+                IndexOpResult::Paused { attempts, duration_secs, timed_out: false } => {
+                    (attempts, duration_secs)
+                }
+                // MAX_DURATION is this run's budget for the rebuild; it resumes next run.
+                IndexOpResult::Failure { attempts, duration_secs, error }
+                    if resumable && error.number() == Some(MAX_DURATION_ERROR) =>
+                {
+                    break IndexOpResult::Paused { attempts, duration_secs, timed_out: false };
+                }
+                other => break other,
+            };
+
+            // The rebuild is paused server-side and the session that ran it is still
+            // blocked on the abandoned request, so resuming needs a new connection.
+            if check_ctrl(ctrl_rx).await.is_some() {
+                paused_by_stop = true;
+                break IndexOpResult::Paused { attempts, duration_secs, timed_out: false };
+            }
+            if window_closed(ctx.deadline) {
+                break IndexOpResult::DeadlineReached { attempts, duration_secs, paused: true };
+            }
+            match create_maintenance_client(profile, Some(db_name), options).await {
+                Ok(c) => client = c,
+                Err(e) => {
+                    break IndexOpResult::Failure {
+                        attempts,
+                        duration_secs,
+                        error: SqlError::Other(format!(
+                            "Reconnect to resume paused rebuild failed: {}",
+                            e
+                        )),
+                    };
+                }
+            }
+            op_sql = resume_index_sql(
                 &index.schema_name,
                 &index.table_name,
                 &index.index_name,
-            ),
-            MaintenanceAction::Skip => unreachable!(),
+                options.resumable_max_duration_minutes,
+                low_priority_wait(options).as_ref(),
+            );
         };
 
-        let op_result = execute_index_operation(&mut client, &sql, options, ctrl_rx, skip_set, db_name).await;
-
         match op_result {
             IndexOpResult::Interrupted => {
                 stopped = true;
@@ -956,23 +2628,183 @@ async fn process_database(
                 break 'indexes;
             }
 
-            IndexOpResult::Failure { attempts, duration_secs, error: err_msg } => {
-                result.success = false;
-                result.errors.push(format!(
-                    "{}.{}.{}: {}",
-                    index.schema_name, index.table_name, index.index_name, err_msg
-                ));
+            IndexOpResult::Paused { attempts, duration_secs, timed_out } => {
+                let paused = if paused_by_stop {
+                    "Paused server-side when the run was stopped; the next run resumes it"
+                } else if timed_out {
+                    "Paused server-side when the request timed out; the next run resumes it"
+                } else {
+                    "Paused itself at MAX_DURATION; the next run resumes it"
+                };
+                result.index_results.push(IndexResult {
+                    schema_name: index.schema_name.clone(),
+                    table_name: index.table_name.clone(),
+                    index_name: index.index_name.clone(),
+                    partition_number: index.target_partition(),
+                    fragmentation_percent: index.fragmentation_percent,
+                    page_count: index.page_count,
+                    forwarded_record_count: index.forwarded_record_count,
+                    action: action.clone(),
+                    success: true,
+                    outcome: IndexOutcome::PausedResumable,
+                    duration_secs,
+                    retry_attempts: attempts,
+                    error: None,
+                    error_number: None,
+                    reason: Some(paused.to_string()),
+                });
+                let _ = app.emit(
+                    "maintenance:index-complete",
+                    IndexCompleteEvent {
+                        profile_id: profile_id.to_string(),
+                        db_name: index.database_name.clone(),
+                        schema_name: index.schema_name.clone(),
+                        table_name: index.table_name.clone(),
+                        index_name: index.index_name.clone(),
+                        partition_number: index.target_partition(),
+                        action,
+                        success: true,
+                        outcome: IndexOutcome::PausedResumable,
+                        duration_secs,
+                        retry_attempts: attempts,
+                        error: None,
+                        reason: Some(paused.to_string()),
+                    },
+                );
+                if paused_by_stop {
+                    stopped = true;
+                    break 'indexes;
+                }
+                // The self-paused request ended normally, and a timed-out one was
+                // replaced by a new connection, so the connection is reusable.
+            }
+
+            IndexOpResult::DeadlineReached { attempts, duration_secs, paused } => {
+                // The request was paused or killed from a side connection, so this
+                // connection is finished; the closed window defers the remaining
+                // indexes before anything would use it again.
+                let deferred = if paused {
+                    "Paused server-side when the maintenance window closed; the next run resumes it"
+                } else {
+                    "Cancelled when the maintenance window closed"
+                };
+                result.indexes_deferred += 1;
+                result.index_results.push(IndexResult {
+                    schema_name: index.schema_name.clone(),
+                    table_name: index.table_name.clone(),
+                    index_name: index.index_name.clone(),
+                    partition_number: index.target_partition(),
+                    fragmentation_percent: index.fragmentation_percent,
+                    page_count: index.page_count,
+                    forwarded_record_count: index.forwarded_record_count,
+                    action: action.clone(),
+                    success: true,
+                    outcome: IndexOutcome::Deferred,
+                    duration_secs,
+                    retry_attempts: attempts,
+                    error: None,
+                    error_number: None,
+                    reason: Some(deferred.to_string()),
+                });
+                let _ = app.emit(
+                    "maintenance:index-complete",
+                    IndexCompleteEvent {
+                        profile_id: profile_id.to_string(),
+                        db_name: index.database_name.clone(),
+                        schema_name: index.schema_name.clone(),
+                        table_name: index.table_name.clone(),
+                        index_name: index.index_name.clone(),
+                        partition_number: index.target_partition(),
+                        action,
+                        success: true,
+                        outcome: IndexOutcome::Deferred,
+                        duration_secs,
+                        retry_attempts: attempts,
+                        error: None,
+                        reason: Some(deferred.to_string()),
+                    },
+                );
+            }
+
+            IndexOpResult::BlockingAborted { attempts, duration_secs, detail } => {
+                // Yielding to production traffic is what the monitor is for, so the
+                // database is not failed for it.
+                result.index_results.push(IndexResult {
+                    schema_name: index.schema_name.clone(),
+                    table_name: index.table_name.clone(),
+                    index_name: index.index_name.clone(),
+                    partition_number: index.target_partition(),
+                    fragmentation_percent: index.fragmentation_percent,
+                    page_count: index.page_count,
+                    forwarded_record_count: index.forwarded_record_count,
+                    action: action.clone(),
+                    success: false,
+                    outcome: IndexOutcome::BlockingAborted,
+                    duration_secs,
+                    retry_attempts: attempts,
+                    error: Some(detail.clone()),
+                    error_number: None,
+                    reason: reason.clone(),
+                });
+                let _ = app.emit(
+                    "maintenance:index-complete",
+                    IndexCompleteEvent {
+                        profile_id: profile_id.to_string(),
+                        db_name: index.database_name.clone(),
+                        schema_name: index.schema_name.clone(),
+                        table_name: index.table_name.clone(),
+                        index_name: index.index_name.clone(),
+                        partition_number: index.target_partition(),
+                        action,
+                        success: false,
+                        outcome: IndexOutcome::BlockingAborted,
+                        duration_secs,
+                        retry_attempts: attempts,
+                        error: Some(detail),
+                        reason: reason.clone(),
+                    },
+                );
+                // The cancelled request was killed or paused under this connection.
+                match create_maintenance_client(profile, Some(db_name), options).await {
+                    Ok(c) => client = c,
+                    Err(e) => {
+                        result.success = false;
+                        result.errors.push(format!("Reconnect after blocking abort failed: {}", e));
+                        break 'indexes;
+                    }
+                }
+            }
+
+            IndexOpResult::Failure { attempts, duration_secs, error } => {
+                let err_msg = error.to_string();
+                let error_number = error.number();
+                // A low-priority self-abort is the configured way of yielding to
+                // production traffic, not a failure of the database.
+                let outcome = if error_number == Some(LOCK_TIMEOUT_ERROR)
+                    && aborts_itself_at_low_priority(online, options)
+                {
+                    IndexOutcome::LowPriorityAborted
+                } else {
+                    result.success = false;
+                    result.errors.push(format!("{}: {}", index_label(index), err_msg));
+                    IndexOutcome::Failed
+                };
                 result.index_results.push(IndexResult {
                     schema_name: index.schema_name.clone(),
                     table_name: index.table_name.clone(),
                     index_name: index.index_name.clone(),
+                    partition_number: index.target_partition(),
                     fragmentation_percent: index.fragmentation_percent,
                     page_count: index.page_count,
+                    forwarded_record_count: index.forwarded_record_count,
                     action: action.clone(),
                     success: false,
+                    outcome,
                     duration_secs,
                     retry_attempts: attempts,
                     error: Some(err_msg.clone()),
+                    error_number,
+                    reason: reason.clone(),
                 });
                 let _ = app.emit(
                     "maintenance:index-complete",
@@ -982,18 +2814,23 @@ async fn process_database(
                         schema_name: index.schema_name.clone(),
                         table_name: index.table_name.clone(),
                         index_name: index.index_name.clone(),
+                        partition_number: index.target_partition(),
                         action,
                         success: false,
+                        outcome,
                         duration_secs,
                         retry_attempts: attempts,
                         error: Some(err_msg),
+                        reason: reason.clone(),
                     },
                 );
             }
 
             IndexOpResult::Success { attempts, duration_secs } => {
                 match action {
-                    MaintenanceAction::Rebuild => result.indexes_rebuilt += 1,
+                    MaintenanceAction::Rebuild | MaintenanceAction::RebuildHeap => {
+                        result.indexes_rebuilt += 1
+                    }
                     MaintenanceAction::Reorganize => result.indexes_reorganized += 1,
                     _ => {}
                 }
@@ -1002,75 +2839,24 @@ async fn process_database(
                     schema_name: index.schema_name.clone(),
                     table_name: index.table_name.clone(),
                     index_name: index.index_name.clone(),
+                    partition_number: index.target_partition(),
                     fragmentation_percent: index.fragmentation_percent,
                     page_count: index.page_count,
+                    forwarded_record_count: index.forwarded_record_count,
                     action: action.clone(),
                     success: true,
+                    outcome: IndexOutcome::Completed,
                     duration_secs,
                     retry_attempts: attempts,
                     error: None,
+                    error_number: None,
+                    reason: reason.clone(),
                 });
 
-                // Update statistics — best effort, bounded by request_timeout if set
-                let stats_sql = update_statistics_sql(
-                    &index.schema_name,
-                    &index.table_name,
-                    &index.index_name,
-                );
-                let stats_fut = async {
-                    if options.request_timeout_ms > 0 {
-                        let _ = timeout(
-                            Duration::from_millis(options.request_timeout_ms),
-                            client.execute(stats_sql.as_str(), &[]),
-                        )
-                        .await;
-                    } else {
-                        let _ = client.execute(stats_sql.as_str(), &[]).await;
-                    }
-                };
-                tokio::select! {
-                    _ = stats_fut => {}
-                    _ = wait_for_stop(ctrl_rx) => {
-                        stopped = true;
-                        // Emit index-complete before breaking so the frontend
-                        // reflects the successful operation that already finished.
-                        let _ = app.emit(
-                            "maintenance:index-complete",
-                            IndexCompleteEvent {
-                                profile_id: profile_id.to_string(),
-                                db_name: index.database_name.clone(),
-                                schema_name: index.schema_name.clone(),
-                                table_name: index.table_name.clone(),
-                                index_name: index.index_name.clone(),
-                                action,
-                                success: true,
-                                duration_secs,
-                                retry_attempts: attempts,
-                                error: None,
-                            },
-                        );
-                        break 'indexes;
-                    }
-                    _ = poll_skip_set(skip_set, db_name) => {
-                        manually_skipped = true;
-                        let _ = app.emit(
-                            "maintenance:index-complete",
-                            IndexCompleteEvent {
-                                profile_id: profile_id.to_string(),
-                                db_name: index.database_name.clone(),
-                                schema_name: index.schema_name.clone(),
-                                table_name: index.table_name.clone(),
-                                index_name: index.index_name.clone(),
-                                action,
-                                success: true,
-                                duration_secs,
-                                retry_attempts: attempts,
-                                error: None,
-                            },
-                        );
-                        break 'indexes;
-                    }
+                if refreshes_statistics(index, &action) {
+                    refreshed.insert(stats_key(index));
                 }
+                let stats_sql = post_operation_statistics_sql(index, &action, options);
 
                 let _ = app.emit(
                     "maintenance:index-complete",
@@ -1080,13 +2866,53 @@ async fn process_database(
                         schema_name: index.schema_name.clone(),
                         table_name: index.table_name.clone(),
                         index_name: index.index_name.clone(),
+                        partition_number: index.target_partition(),
                         action,
                         success: true,
+                        outcome: IndexOutcome::Completed,
                         duration_secs,
                         retry_attempts: attempts,
                         error: None,
+                        reason: reason.clone(),
                     },
                 );
+
+                let Some(stats_sql) = stats_sql else { continue 'indexes };
+                match execute_statistics_update(ctx, &mut client, &stats_sql, db_name).await {
+                    StatisticsUpdate::Done { duration_secs, attempts, error } => {
+                        let is_heap = index.index_kind == IndexKind::Heap;
+                        let stats_result = StatisticsResult {
+                            schema_name: index.schema_name.clone(),
+                            table_name: index.table_name.clone(),
+                            stats_name: index.index_name.clone(),
+                            is_index_statistics: !is_heap,
+                            rows: None,
+                            modification_counter: None,
+                            last_updated: None,
+                            sampling: StatisticsSampling::Fullscan,
+                            success: error.is_none(),
+                            duration_secs,
+                            retry_attempts: attempts,
+                            error,
+                            reason: if is_heap {
+                                "All statistics of the heap's table, after its rebuild".to_string()
+                            } else {
+                                "Not refreshed by the index operation".to_string()
+                            },
+                        };
+                        record_statistics_result(ctx, db_name, &mut result, stats_result);
+                    }
+                    StatisticsUpdate::Interrupted => {
+                        stopped = true;
+                        break 'indexes;
+                    }
+                    StatisticsUpdate::ManuallySkipped => {
+                        manually_skipped = true;
+                        break 'indexes;
+                    }
+                    // The closed window defers the remaining indexes.
+                    StatisticsUpdate::DeadlineReached => {}
+                }
             }
         }
     }
@@ -1096,8 +2922,18 @@ async fn process_database(
         manually_skipped = true;
     }
 
-    // DBCC FREEPROCCACHE — best effort, cancellable on stop
-    if !stopped && !manually_skipped && options.free_proc_cache && (result.indexes_rebuilt > 0 || result.indexes_reorganized > 0) {
+    // Statistics pass — after the index operations, so statistics a rebuild has
+    // just refreshed are not updated a second time.
+    if !stopped && !manually_skipped && options.update_statistics && !window_closed(ctx.deadline) {
+        let (stats_stopped, stats_skipped) =
+            run_statistics_pass(ctx, &mut client, db_name, &refreshed, &mut result).await;
+        stopped = stats_stopped;
+        manually_skipped = stats_skipped;
+    }
+
+    // DBCC FREEPROCCACHE — best effort, cancellable on stop. Skipped once the window
+    // has closed: the connection may still be busy with an abandoned operation.
+    if !stopped && !manually_skipped && !window_closed(ctx.deadline) && options.free_proc_cache && (result.indexes_rebuilt > 0 || result.indexes_reorganized > 0) {
         tokio::select! {
             _ = client.execute(FREE_PROC_CACHE, &[]) => {}
             _ = wait_for_stop(ctrl_rx) => { stopped = true; }
@@ -1113,3 +2949,281 @@ async fn process_database(
 
     (result, stopped)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(name: &str, fragmentation_percent: f64) -> IndexInfo {
+        IndexInfo {
+            database_name: "Sales".to_string(),
+            schema_name: "dbo".to_string(),
+            table_name: "Orders".to_string(),
+            index_name: name.to_string(),
+            fragmentation_percent,
+            page_count: 5000,
+            page_density_percent: None,
+            record_count: None,
+            ghost_record_count: None,
+            partition_number: 1,
+            partition_count: 1,
+            forwarded_record_count: None,
+            index_kind: IndexKind::Rowstore,
+            has_legacy_lob: false,
+            has_max_lob: false,
+            paused_rebuild_percent: None,
+            columnstore: None,
+            excluded_by: None,
+        }
+    }
+
+    fn server(edition: &str, engine_edition: i32, major_version: i32) -> ServerInfo {
+        ServerInfo { edition: edition.to_string(), engine_edition, major_version }
+    }
+
+    fn enterprise() -> ServerInfo {
+        server("Enterprise Edition (64-bit)", 3, 16)
+    }
+
+    fn heap(forwarded_record_count: i64, record_count: i64) -> IndexInfo {
+        IndexInfo {
+            index_kind: IndexKind::Heap,
+            forwarded_record_count: Some(forwarded_record_count),
+            record_count: Some(record_count),
+            ..index("HEAP", 0.0)
+        }
+    }
+
+    fn columnstore(deleted_percent: f64, delta_row_groups: i32) -> IndexInfo {
+        IndexInfo {
+            index_kind: IndexKind::ClusteredColumnstore,
+            columnstore: Some(ColumnstoreStats {
+                total_rows: 1_000_000,
+                deleted_rows: (deleted_percent * 10_000.0) as i64,
+                open_row_groups: 0,
+                closed_row_groups: delta_row_groups,
+                compressed_row_groups: 8,
+            }),
+            ..index("CCI_Orders", deleted_percent)
+        }
+    }
+
+    fn session(session_id: i32, wait_secs: f64) -> BlockingSession {
+        BlockingSession {
+            session_id,
+            wait_secs,
+            wait_type: "LCK_M_S".to_string(),
+            login_name: "app".to_string(),
+            host_name: String::new(),
+            program_name: String::new(),
+        }
+    }
+
+    #[test]
+    fn online_rebuild_falls_back_by_edition() {
+        let options = MaintenanceOptions::default();
+        let online = decide_index(&index("PK_Orders", 45.0), &options, &enterprise());
+        assert_eq!(online.action, MaintenanceAction::Rebuild);
+        assert!(online.online && online.reason.is_none());
+
+        let standard = server("Standard Edition (64-bit)", 2, 16);
+        let offline = decide_index(&index("PK_Orders", 45.0), &options, &standard);
+        assert_eq!(offline.action, MaintenanceAction::Rebuild);
+        assert!(!offline.online && !offline.resumable);
+        assert_eq!(
+            offline.reason.as_deref(),
+            Some("Online rebuild is not available on Standard Edition (64-bit); rebuilt offline")
+        );
+
+        let skip =
+            MaintenanceOptions { online_fallback: OnlineFallback::Skip, ..Default::default() };
+        let skipped = decide_index(&index("PK_Orders", 45.0), &skip, &standard);
+        assert_eq!(skipped.action, MaintenanceAction::Skip);
+        assert!(skipped.reason.unwrap().ends_with("skipped by online fallback policy"));
+
+        // Azure SQL Database reports a 12.x version but has every online feature.
+        let resumable = MaintenanceOptions { resumable_rebuild: true, ..Default::default() };
+        let azure = server("SQL Azure", 5, 12);
+        let decision = decide_index(&index("PK_Orders", 45.0), &resumable, &azure);
+        assert!(decision.online && decision.resumable);
+        let sql_2016 = server("Enterprise Edition (64-bit)", 3, 13);
+        let decision = decide_index(&index("PK_Orders", 45.0), &resumable, &sql_2016);
+        assert!(decision.online && !decision.resumable);
+    }
+
+    #[test]
+    fn reorganize_only_never_rebuilds() {
+        let options = MaintenanceOptions { reorganize_only: true, ..Default::default() };
+        let decision = decide_index(&index("PK_Orders", 45.0), &options, &enterprise());
+        assert_eq!(decision.action, MaintenanceAction::Reorganize);
+        assert!(!decision.online);
+        assert!(decision.reason.unwrap().ends_with("(reorganize only)"));
+
+        let decision = decide_index(&heap(50, 100), &options, &enterprise());
+        assert_eq!(decision.action, MaintenanceAction::Skip);
+        assert!(decision.reason.unwrap().starts_with("Heaps cannot be reorganized"));
+
+        let decision = decide_index(&index("IX_Orders_Status", 15.0), &options, &enterprise());
+        assert_eq!(decision.action, MaintenanceAction::Reorganize);
+        assert!(decision.reason.is_none());
+    }
+
+    #[test]
+    fn columnstore_and_heap_actions() {
+        let options = MaintenanceOptions::default();
+        assert_eq!(determine_action(&columnstore(10.0, 0), &options), MaintenanceAction::Skip);
+        assert_eq!(
+            determine_action(&columnstore(25.0, 0), &options),
+            MaintenanceAction::Reorganize
+        );
+        assert_eq!(determine_action(&columnstore(60.0, 0), &options), MaintenanceAction::Rebuild);
+        let delta =
+            MaintenanceOptions { columnstore_delta_rowgroup_threshold: 3, ..Default::default() };
+        assert_eq!(determine_action(&columnstore(10.0, 2), &delta), MaintenanceAction::Skip);
+        assert_eq!(determine_action(&columnstore(10.0, 3), &delta), MaintenanceAction::Reorganize);
+
+        assert_eq!(determine_action(&heap(5, 100), &options), MaintenanceAction::Skip);
+        assert_eq!(determine_action(&heap(10, 100), &options), MaintenanceAction::RebuildHeap);
+        assert_eq!(determine_action(&heap(10, 0), &options), MaintenanceAction::Skip);
+        let fragmented = IndexInfo { fragmentation_percent: 90.0, ..heap(0, 100) };
+        assert_eq!(determine_action(&fragmented, &options), MaintenanceAction::Skip);
+        let by_extents =
+            MaintenanceOptions { heap_fragmentation_threshold: 30.0, ..Default::default() };
+        assert_eq!(determine_action(&fragmented, &by_extents), MaintenanceAction::RebuildHeap);
+    }
+
+    #[test]
+    fn prioritize_indexes_by_strategy() {
+        let large = IndexInfo { page_count: 5000, ..index("IX_Large", 80.0) };
+        let small = IndexInfo { page_count: 100, ..index("IX_Small", 40.0) };
+        let medium = IndexInfo { page_count: 1000, ..index("IX_Medium", 20.0) };
+        let key = |i: &IndexInfo| {
+            index_key(&i.database_name, &i.schema_name, &i.table_name, &i.index_name, None)
+        };
+        let usage = HashMap::from([(stats_key(&medium), 500), (stats_key(&small), 10)]);
+        let last_maintained = HashMap::from([
+            (key(&large), "2026-10-01T02:00:00Z".to_string()),
+            (key(&medium), "2026-09-01T02:00:00Z".to_string()),
+        ]);
+        let order = |priority| {
+            let mut indexes = vec![medium.clone(), small.clone(), large.clone()];
+            prioritize_indexes(&mut indexes, priority, &usage, &last_maintained);
+            indexes.into_iter().map(|i| i.index_name).collect::<Vec<_>>()
+        };
+
+        assert_eq!(order(IndexPriority::Fragmentation), ["IX_Large", "IX_Small", "IX_Medium"]);
+        assert_eq!(order(IndexPriority::LargestBenefit), ["IX_Large", "IX_Medium", "IX_Small"]);
+        assert_eq!(order(IndexPriority::MostUsed), ["IX_Medium", "IX_Small", "IX_Large"]);
+        assert_eq!(order(IndexPriority::SmallestFirst), ["IX_Small", "IX_Medium", "IX_Large"]);
+        assert_eq!(
+            order(IndexPriority::LeastRecentlyMaintained),
+            ["IX_Small", "IX_Medium", "IX_Large"]
+        );
+    }
+
+    #[test]
+    fn run_deadline_takes_the_earlier_limit() {
+        let secs_left = |options: MaintenanceOptions| {
+            run_deadline(&options).map(|d| d.saturating_duration_since(Instant::now()).as_secs())
+        };
+        assert_eq!(secs_left(MaintenanceOptions::default()), None);
+        let invalid =
+            MaintenanceOptions { window_end_time: "25:00".to_string(), ..Default::default() };
+        assert_eq!(secs_left(invalid), None);
+
+        let thirty_minutes = MaintenanceOptions { max_run_minutes: 30, ..Default::default() };
+        assert!((1790..=1800).contains(&secs_left(thirty_minutes.clone()).unwrap()));
+
+        // The end time has minute precision, so it closes up to a minute early.
+        let in_two_hours = (chrono::Local::now() + chrono::Duration::hours(2)).format("%H:%M");
+        let window = MaintenanceOptions {
+            window_end_time: in_two_hours.to_string(),
+            ..Default::default()
+        };
+        assert!((7130..=7200).contains(&secs_left(window.clone()).unwrap()));
+        let both = MaintenanceOptions { max_run_minutes: 30, ..window };
+        assert!((1790..=1800).contains(&secs_left(both).unwrap()));
+    }
+
+    #[test]
+    fn blocking_abort_reason_thresholds() {
+        let snapshot = BlockingSnapshot {
+            blocking: Some(session(57, 90.0)),
+            blocked_by: Some(session(61, 30.0)),
+        };
+        assert_eq!(blocking_abort_reason(&snapshot, &MaintenanceOptions::default()), None);
+
+        let blocking = MaintenanceOptions { max_blocking_secs: 120, ..Default::default() };
+        assert_eq!(blocking_abort_reason(&snapshot, &blocking), None);
+        let blocking = MaintenanceOptions { max_blocking_secs: 90, ..Default::default() };
+        assert_eq!(
+            blocking_abort_reason(&snapshot, &blocking).as_deref(),
+            Some("aborted: blocking — session 57 waited 90s on this operation (LCK_M_S, login app)")
+        );
+
+        let blocked = MaintenanceOptions { max_blocked_secs: 60, ..Default::default() };
+        assert_eq!(blocking_abort_reason(&snapshot, &blocked), None);
+        let blocked = MaintenanceOptions { max_blocked_secs: 30, ..Default::default() };
+        assert_eq!(
+            blocking_abort_reason(&snapshot, &blocked).as_deref(),
+            Some("aborted: blocking — blocked for 30s by session 61 (LCK_M_S, login app)")
+        );
+    }
+
+    #[test]
+    fn plan_classifies_and_defers_like_a_run() {
+        let server = enterprise();
+        let options = MaintenanceOptions::default();
+        let rebuild_long = index("IX_Orders_Customer", 60.0);
+        let key = duration_key(
+            "Sales",
+            "dbo",
+            "Orders",
+            "IX_Orders_Customer",
+            None,
+            &MaintenanceAction::Rebuild,
+        );
+        let estimates = HashMap::from([(key, 900.0)]);
+        let mut left = Some(600.0);
+        let mut window = PlanWindow { left: &mut left, estimates: &estimates };
+
+        let skip = plan_index(&index("IX_Orders_Date", 2.0), &options, &server, &mut window);
+        assert_eq!(skip.action, MaintenanceAction::Skip);
+        assert!(skip.sql.is_none() && !skip.deferred);
+
+        let reorganize = plan_index(&index("IX_Orders_Status", 15.0), &options, &server, &mut window);
+        assert_eq!(reorganize.action, MaintenanceAction::Reorganize);
+        assert!(!reorganize.deferred);
+        // With the statistics pass off, a reorganize is followed by a FULLSCAN update.
+        assert_eq!(
+            reorganize.statistics_sql.as_deref(),
+            Some("UPDATE STATISTICS [dbo].[Orders] [IX_Orders_Status] WITH FULLSCAN;")
+        );
+
+        let rebuild = plan_index(&index("PK_Orders", 45.0), &options, &server, &mut window);
+        assert_eq!(rebuild.action, MaintenanceAction::Rebuild);
+        assert!(!rebuild.deferred);
+        assert!(rebuild.sql.as_deref().unwrap().contains("REBUILD"));
+        assert!(rebuild.statistics_sql.is_none());
+
+        // Took 900s last time; only 600s are left.
+        let deferred = plan_index(&rebuild_long, &options, &server, &mut window);
+        assert_eq!(deferred.action, MaintenanceAction::Rebuild);
+        assert!(deferred.deferred);
+        assert!(deferred.reason.unwrap().starts_with("Deferred:"));
+
+        let mut closed = Some(0.0);
+        let mut window = PlanWindow { left: &mut closed, estimates: &estimates };
+        let late = plan_index(&index("IX_Orders_Status", 15.0), &options, &server, &mut window);
+        assert!(late.deferred);
+
+        let with_pass = MaintenanceOptions { update_statistics: true, ..Default::default() };
+        let mut window = PlanWindow { left: &mut None, estimates: &estimates };
+        let reorganize = index("IX_Orders_Status", 15.0);
+        assert!(plan_index(&reorganize, &with_pass, &server, &mut window).statistics_sql.is_none());
+
+        let mut unlimited = None;
+        let mut window = PlanWindow { left: &mut unlimited, estimates: &estimates };
+        assert!(!plan_index(&rebuild_long, &options, &server, &mut window).deferred);
+    }
+}
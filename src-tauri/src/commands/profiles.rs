@@ -88,8 +88,9 @@ fn save_password_map(map: &HashMap<String, String>) {
 
 fn store_password(profile_id: &str, password: &str) {
     // Empty password means "do not update" — preserves the existing keychain entry.
-    // SQL Server auth always requires a password, so an empty value here signals
-    // that the user left the field blank during an edit (intending no change).
+    // Both SQL Server and Windows credential auth require a password, so an empty
+    // value here signals that the user left the field blank during an edit
+    // (intending no change).
     if password.is_empty() {
         return;
     }
@@ -0,0 +1,306 @@
+use crate::models::types::{AuthType, ServerProfile};
+use serde::Deserialize;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_AUTHORITY_HOST: &str = "https://login.microsoftonline.com";
+
+/// Scope requested for Azure SQL Database / Managed Instance access tokens.
+const AZURE_SQL_SCOPE: &str = "https://database.windows.net/.default";
+
+/// A cached token is replaced this long before it expires, so a connection
+/// opened with it does not race the expiry.
+const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(300);
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    /// Lifetime in seconds; Entra ID always sends it.
+    #[serde(default)]
+    expires_in: u64,
+}
+
+/// Access token from a client-credentials grant.
+#[derive(Debug)]
+pub struct AccessToken {
+    pub token: String,
+    /// Seconds the token is valid for, as reported by the identity endpoint.
+    pub expires_in: u64,
+}
+
+struct CachedToken {
+    /// Fingerprint of the endpoint and credentials the token was issued for.
+    credentials: u64,
+    token: String,
+    refresh_at: Instant,
+}
+
+/// Service principal tokens by profile ID. A run opens a connection per database,
+/// side connection and reconnect; each reuses the token instead of asking the
+/// identity endpoint again.
+fn token_cache() -> &'static Mutex<HashMap<String, CachedToken>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, CachedToken>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn credentials_fingerprint(endpoint: &str, client_id: &str, client_secret: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    (endpoint, client_id, client_secret).hash(&mut hasher);
+    hasher.finish()
+}
+
+#[derive(Deserialize)]
+struct TokenErrorResponse {
+    error: String,
+    #[serde(default)]
+    error_description: Option<String>,
+}
+
+/// OAuth2 v2.0 token endpoint for `tenant_id`. `authority_host` replaces the
+/// public-cloud host, e.g. for sovereign clouds or a local mock token server.
+pub fn token_endpoint(authority_host: Option<&str>, tenant_id: &str) -> String {
+    let host = authority_host
+        .map(str::trim)
+        .filter(|h| !h.is_empty())
+        .unwrap_or(DEFAULT_AUTHORITY_HOST)
+        .trim_end_matches('/');
+    format!("{}/{}/oauth2/v2.0/token", host, tenant_id.trim())
+}
+
+/// Returns an access token for the profile's Entra ID auth type.
+/// Pre-supplied tokens are returned as-is; service principals go through the
+/// client-credentials flow against the profile's authority host, and the token
+/// is reused until shortly before it expires.
+pub async fn acquire_token(profile: &ServerProfile, timeout_ms: u64) -> Result<String, String> {
+    match profile.auth_type {
+        AuthType::AzureAdToken => {
+            let token = profile.password.trim();
+            if token.is_empty() {
+                return Err("Azure AD access token is empty".to_string());
+            }
+            Ok(token.to_string())
+        }
+        AuthType::AzureAdServicePrincipal => {
+            if profile.tenant_id.trim().is_empty() {
+                return Err("Azure AD tenant ID is required for service principal auth".to_string());
+            }
+            if profile.username.trim().is_empty() || profile.password.is_empty() {
+                return Err(
+                    "Azure AD service principal auth requires a client ID and client secret"
+                        .to_string(),
+                );
+            }
+            let endpoint =
+                token_endpoint(profile.authority_host.as_deref(), &profile.tenant_id);
+            let client_id = profile.username.trim();
+            let credentials = credentials_fingerprint(&endpoint, client_id, &profile.password);
+            if let Some(cached) = token_cache().lock().unwrap().get(&profile.id) {
+                if cached.credentials == credentials && Instant::now() < cached.refresh_at {
+                    return Ok(cached.token.clone());
+                }
+            }
+            let grant =
+                fetch_client_credentials_token(&endpoint, client_id, &profile.password, timeout_ms)
+                    .await?;
+            let lifetime = Duration::from_secs(grant.expires_in);
+            token_cache().lock().unwrap().insert(
+                profile.id.clone(),
+                CachedToken {
+                    credentials,
+                    token: grant.token.clone(),
+                    refresh_at: Instant::now() + lifetime.saturating_sub(TOKEN_REFRESH_MARGIN),
+                },
+            );
+            Ok(grant.token)
+        }
+        AuthType::SqlServer | AuthType::WindowsCredentials => {
+            Err("Profile does not use Azure AD authentication".to_string())
+        }
+    }
+}
+
+/// Client-credentials grant against `endpoint`. A `timeout_ms` of 0 disables the timeout.
+pub async fn fetch_client_credentials_token(
+    endpoint: &str,
+    client_id: &str,
+    client_secret: &str,
+    timeout_ms: u64,
+) -> Result<AccessToken, String> {
+    let mut builder = reqwest::Client::builder();
+    if timeout_ms > 0 {
+        builder = builder.timeout(Duration::from_millis(timeout_ms));
+    }
+    let http = builder.build().map_err(|e| e.to_string())?;
+
+    let response = http
+        .post(endpoint)
+        .form(&[
+            ("grant_type", "client_credentials"),
+            ("client_id", client_id),
+            ("client_secret", client_secret),
+            ("scope", AZURE_SQL_SCOPE),
+        ])
+        .send()
+        .await
+        .map_err(|e| format!("Azure AD token request to {} failed: {}", endpoint, e))?;
+
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| format!("Azure AD token response could not be read: {}", e))?;
+
+    if !status.is_success() {
+        return Err(match serde_json::from_str::<TokenErrorResponse>(&body) {
+            Ok(err) => format!(
+                "Azure AD token request rejected ({}): {}",
+                err.error,
+                err.error_description.unwrap_or_default()
+            ),
+            Err(_) => format!("Azure AD token request failed with HTTP {}", status),
+        });
+    }
+
+    serde_json::from_str::<TokenResponse>(&body)
+        .map(|t| AccessToken { token: t.access_token, expires_in: t.expires_in })
+        .map_err(|e| format!("Azure AD token response is malformed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serves one HTTP response on a local port and hands back the request it got.
+    async fn mock_token_server(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let host = format!("http://{}", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 4096];
+            loop {
+                let n = socket.read(&mut buf).await.unwrap();
+                assert!(n > 0, "client closed the connection before sending the request");
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request);
+                if let Some(end) = text.find("\r\n\r\n") {
+                    let length = text[..end]
+                        .lines()
+                        .find_map(|l| {
+                            let l = l.to_ascii_lowercase();
+                            l.strip_prefix("content-length:").map(|v| v.trim().parse().unwrap())
+                        })
+                        .unwrap_or(0);
+                    if request.len() >= end + 4 + length {
+                        break;
+                    }
+                }
+            }
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+                 Connection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8_lossy(&request).into_owned()
+        });
+        (host, handle)
+    }
+
+    #[test]
+    fn token_endpoint_uses_authority_host() {
+        assert_eq!(
+            token_endpoint(None, " contoso "),
+            "https://login.microsoftonline.com/contoso/oauth2/v2.0/token"
+        );
+        assert_eq!(
+            token_endpoint(Some(" "), "contoso"),
+            "https://login.microsoftonline.com/contoso/oauth2/v2.0/token"
+        );
+        assert_eq!(
+            token_endpoint(Some("https://login.microsoftonline.us/"), "contoso"),
+            "https://login.microsoftonline.us/contoso/oauth2/v2.0/token"
+        );
+    }
+
+    #[tokio::test]
+    async fn client_credentials_token_is_returned() {
+        let (host, server) = mock_token_server(
+            "200 OK",
+            r#"{"token_type":"Bearer","expires_in":3599,"access_token":"eyJ0eXAi"}"#,
+        )
+        .await;
+        let endpoint = token_endpoint(Some(&host), "contoso");
+        let grant = fetch_client_credentials_token(&endpoint, "app-id", "s3cret", 5000).await;
+        let grant = grant.unwrap();
+        assert_eq!((grant.token.as_str(), grant.expires_in), ("eyJ0eXAi", 3599));
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /contoso/oauth2/v2.0/token "));
+        assert!(request.contains("grant_type=client_credentials"));
+        assert!(request.contains("client_id=app-id"));
+        assert!(request.contains("client_secret=s3cret"));
+        assert!(request.contains("scope=https%3A%2F%2Fdatabase.windows.net%2F.default"));
+    }
+
+    #[tokio::test]
+    async fn service_principal_token_is_cached_per_profile() {
+        // The mock server answers a single request, so a second fetch would fail.
+        let (host, server) = mock_token_server(
+            "200 OK",
+            r#"{"token_type":"Bearer","expires_in":3599,"access_token":"cached"}"#,
+        )
+        .await;
+        let profile = ServerProfile {
+            id: "cache-test".to_string(),
+            auth_type: AuthType::AzureAdServicePrincipal,
+            username: "app-id".to_string(),
+            password: "s3cret".to_string(),
+            tenant_id: "contoso".to_string(),
+            authority_host: Some(host),
+            ..Default::default()
+        };
+        assert_eq!(acquire_token(&profile, 5000).await.unwrap(), "cached");
+        server.await.unwrap();
+        assert_eq!(acquire_token(&profile, 5000).await.unwrap(), "cached");
+
+        // Changed credentials are not served from the cache.
+        let rotated = ServerProfile { password: "rotated".to_string(), ..profile };
+        assert!(acquire_token(&rotated, 5000).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn client_credentials_errors_are_reported() {
+        let (host, _server) = mock_token_server(
+            "401 Unauthorized",
+            r#"{"error":"invalid_client","error_description":"AADSTS7000215: Invalid client secret."}"#,
+        )
+        .await;
+        let endpoint = token_endpoint(Some(&host), "contoso");
+        let err = fetch_client_credentials_token(&endpoint, "app-id", "wrong", 5000).await;
+        assert_eq!(
+            err.unwrap_err(),
+            "Azure AD token request rejected (invalid_client): AADSTS7000215: Invalid client secret."
+        );
+
+        let (host, _server) = mock_token_server("502 Bad Gateway", "<html>bad gateway</html>").await;
+        let endpoint = token_endpoint(Some(&host), "contoso");
+        let err = fetch_client_credentials_token(&endpoint, "app-id", "s3cret", 5000).await;
+        assert_eq!(err.unwrap_err(), "Azure AD token request failed with HTTP 502 Bad Gateway");
+
+        let (host, _server) = mock_token_server("200 OK", r#"{"token_type":"Bearer"}"#).await;
+        let endpoint = token_endpoint(Some(&host), "contoso");
+        let err = fetch_client_credentials_token(&endpoint, "app-id", "s3cret", 5000).await;
+        assert!(err.unwrap_err().starts_with("Azure AD token response is malformed"));
+    }
+}
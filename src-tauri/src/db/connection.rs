@@ -1,18 +1,96 @@
-use crate::models::types::ServerProfile;
+use crate::db::azure_ad::acquire_token;
+use crate::db::error::SqlError;
+use crate::db::sql_browser::resolve_instance_port;
+use crate::db::queries::session_settings_sql;
+use crate::models::types::{AuthType, MaintenanceOptions, ServerProfile};
 use tiberius::{AuthMethod, Client, Config, EncryptionLevel};
 use tokio::net::TcpStream;
 use tokio::time::{timeout, Duration};
 use tokio_util::compat::{Compat, TokioAsyncWriteCompatExt};
 
+async fn auth_method(profile: &ServerProfile, timeout_ms: u64) -> Result<AuthMethod, SqlError> {
+    match profile.auth_type {
+        AuthType::SqlServer => Ok(AuthMethod::sql_server(&profile.username, &profile.password)),
+        AuthType::WindowsCredentials => windows_auth_method(profile),
+        AuthType::AzureAdServicePrincipal | AuthType::AzureAdToken => {
+            let token = acquire_token(profile, timeout_ms).await.map_err(SqlError::Other)?;
+            Ok(AuthMethod::aad_token(token))
+        }
+    }
+}
+
+#[cfg(windows)]
+fn windows_auth_method(profile: &ServerProfile) -> Result<AuthMethod, SqlError> {
+    Ok(AuthMethod::windows(&profile.username, &profile.password))
+}
+
+// tiberius only ships an NTLM implementation on Windows.
+#[cfg(not(windows))]
+fn windows_auth_method(_profile: &ServerProfile) -> Result<AuthMethod, SqlError> {
+    Err(SqlError::Other("Windows authentication requires running on Windows".to_string()))
+}
+
+/// Splits the profile's target into host and optional instance name. An explicit
+/// `instance_name` wins; otherwise a `HOST\INSTANCE` server string is honoured.
+fn host_and_instance(profile: &ServerProfile) -> (&str, Option<&str>) {
+    let (host, server_instance) = match profile.server.split_once('\\') {
+        Some((host, instance)) => (host.trim(), Some(instance.trim())),
+        None => (profile.server.trim(), None),
+    };
+    let explicit = profile.instance_name.trim();
+    let instance = if explicit.is_empty() { server_instance } else { Some(explicit) };
+    (host, instance.filter(|i| !i.is_empty()))
+}
+
 pub async fn create_client(
     profile: &ServerProfile,
     database: Option<&str>,
     connect_timeout_ms: u64,
-) -> Result<Client<Compat<TcpStream>>, String> {
+) -> Result<Client<Compat<TcpStream>>, SqlError> {
+    connect(profile, database, connect_timeout_ms, None).await
+}
+
+/// Opens a connection for a maintenance run: logged in under the configured
+/// application name, with the run's lock timeout and deadlock priority set.
+pub async fn create_maintenance_client(
+    profile: &ServerProfile,
+    database: Option<&str>,
+    options: &MaintenanceOptions,
+) -> Result<Client<Compat<TcpStream>>, SqlError> {
+    let application_name = options.application_name.trim();
+    let mut client = connect(
+        profile,
+        database,
+        options.connection_timeout_ms,
+        (!application_name.is_empty()).then_some(application_name),
+    )
+    .await?;
+    if let Some(sql) = session_settings_sql(options) {
+        client
+            .execute(sql, &[])
+            .await
+            .map_err(|e| SqlError::from(e).context("Failed to apply session settings"))?;
+    }
+    Ok(client)
+}
+
+async fn connect(
+    profile: &ServerProfile,
+    database: Option<&str>,
+    connect_timeout_ms: u64,
+    application_name: Option<&str>,
+) -> Result<Client<Compat<TcpStream>>, SqlError> {
+    // Named instances listen on a dynamic port that only the SQL Browser knows.
+    let (server_host, instance) = host_and_instance(profile);
+    let server_port = match instance {
+        Some(instance) => resolve_instance_port(server_host, instance).await.map_err(SqlError::Io)?,
+        None => profile.port,
+    };
+
     let mut config = Config::new();
-    config.host(&profile.server);
-    config.port(profile.port);
-    config.authentication(AuthMethod::sql_server(&profile.username, &profile.password));
+    config.host(server_host);
+    config.port(server_port);
+    config.authentication(auth_method(profile, connect_timeout_ms).await?);
 
     if profile.encrypt {
         config.encryption(EncryptionLevel::Required);
@@ -28,17 +106,21 @@ pub async fn create_client(
         config.database(db);
     }
 
+    if let Some(name) = application_name {
+        config.application_name(name);
+    }
+
     let tcp = if connect_timeout_ms == 0 {
         TcpStream::connect(config.get_addr())
             .await
-            .map_err(|e| format!("TCP connect to {}:{} failed: {}", profile.server, profile.port, e))?
+            .map_err(|e| SqlError::Io(format!("TCP connect to {}:{} failed: {}", server_host, server_port, e)))?
     } else {
         timeout(Duration::from_millis(connect_timeout_ms), TcpStream::connect(config.get_addr()))
             .await
-            .map_err(|_| format!("TCP connect to {}:{} timed out after {}ms", profile.server, profile.port, connect_timeout_ms))?
-            .map_err(|e| format!("TCP connect to {}:{} failed: {}", profile.server, profile.port, e))?
+            .map_err(|_| SqlError::Io(format!("TCP connect to {}:{} timed out after {}ms", server_host, server_port, connect_timeout_ms)))?
+            .map_err(|e| SqlError::Io(format!("TCP connect to {}:{} failed: {}", server_host, server_port, e)))?
     };
-    tcp.set_nodelay(true).map_err(|e| e.to_string())?;
+    tcp.set_nodelay(true).map_err(|e| SqlError::Io(e.to_string()))?;
 
     match Client::connect(config.clone(), tcp.compat_write()).await {
         Ok(client) => Ok(client),
@@ -51,19 +133,19 @@ pub async fn create_client(
             let tcp = if connect_timeout_ms == 0 {
                 TcpStream::connect(routing_config.get_addr())
                     .await
-                    .map_err(|e| format!("Routing TCP connect to {}:{} failed: {}", host, port, e))?
+                    .map_err(|e| SqlError::Io(format!("Routing TCP connect to {}:{} failed: {}", host, port, e)))?
             } else {
                 timeout(Duration::from_millis(connect_timeout_ms), TcpStream::connect(routing_config.get_addr()))
                     .await
-                    .map_err(|_| format!("Routing TCP connect to {}:{} timed out", host, port))?
-                    .map_err(|e| format!("Routing TCP connect to {}:{} failed: {}", host, port, e))?
+                    .map_err(|_| SqlError::Io(format!("Routing TCP connect to {}:{} timed out", host, port)))?
+                    .map_err(|e| SqlError::Io(format!("Routing TCP connect to {}:{} failed: {}", host, port, e)))?
             };
-            tcp.set_nodelay(true).map_err(|e| e.to_string())?;
+            tcp.set_nodelay(true).map_err(|e| SqlError::Io(e.to_string()))?;
 
             Client::connect(routing_config, tcp.compat_write())
                 .await
-                .map_err(|e| format!("Routing connection failed: {}", e))
+                .map_err(|e| SqlError::from(e).context("Routing connection failed"))
         }
-        Err(e) => Err(format!("Connection failed: {}", e)),
+        Err(e) => Err(SqlError::from(e).context("Connection failed")),
     }
 }
@@ -0,0 +1,148 @@
+use std::fmt;
+
+/// Why a SQL request failed. Server errors keep their number, severity and
+/// state, so they are classified by number rather than by their message, which
+/// the server localizes.
+#[derive(Debug, Clone)]
+pub enum SqlError {
+    Server { number: u32, severity: u8, state: u8, message: String },
+    /// The request did not finish within the request timeout.
+    Timeout { timeout_ms: u64 },
+    /// The connection failed underneath the request.
+    Io(String),
+    /// Any other failure, including ones raised outside the driver.
+    Other(String),
+}
+
+impl SqlError {
+    /// The SQL Server error number, when the server raised the error.
+    pub fn number(&self) -> Option<u32> {
+        match self {
+            SqlError::Server { number, .. } => Some(*number),
+            _ => None,
+        }
+    }
+
+    /// Server errors are retried when their number is listed; timeouts and
+    /// dropped connections when their retries are enabled.
+    pub fn is_retryable(
+        &self,
+        retryable_numbers: &[u32],
+        timeouts: bool,
+        connection_errors: bool,
+    ) -> bool {
+        match self {
+            SqlError::Server { number, .. } => retryable_numbers.contains(number),
+            SqlError::Timeout { .. } => timeouts,
+            SqlError::Io(_) => connection_errors,
+            SqlError::Other(_) => false,
+        }
+    }
+
+    /// Whether the connection that raised this error must be replaced: a
+    /// timed-out request still occupies it, and a failed one is gone.
+    pub fn needs_new_connection(&self) -> bool {
+        matches!(self, SqlError::Timeout { .. } | SqlError::Io(_))
+    }
+
+    /// Prefixes the message with what failed, keeping the classification.
+    /// Server errors already identify themselves by number and are left as is.
+    pub fn context(self, what: &str) -> Self {
+        match self {
+            SqlError::Io(message) => SqlError::Io(format!("{}: {}", what, message)),
+            SqlError::Other(message) => SqlError::Other(format!("{}: {}", what, message)),
+            other => other,
+        }
+    }
+}
+
+impl From<tiberius::error::Error> for SqlError {
+    fn from(err: tiberius::error::Error) -> Self {
+        match err {
+            tiberius::error::Error::Server(token) => SqlError::Server {
+                number: token.code(),
+                severity: token.class(),
+                state: token.state(),
+                message: token.message().to_string(),
+            },
+            tiberius::error::Error::Io { .. } => SqlError::Io(err.to_string()),
+            other => SqlError::Other(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for SqlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SqlError::Server { number, severity, state, message } => {
+                write!(f, "Msg {}, Level {}, State {}: {}", number, severity, state, message)
+            }
+            SqlError::Timeout { timeout_ms } => {
+                write!(f, "SQL request timed out after {}ms", timeout_ms)
+            }
+            SqlError::Io(message) | SqlError::Other(message) => f.write_str(message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retryable_by_number_not_message() {
+        let server = |number, message: &str| SqlError::Server {
+            number,
+            severity: 13,
+            state: 51,
+            message: message.to_string(),
+        };
+        let retryable = [1205, 1222];
+        let deadlock = server(1205, "Die Transaktion wurde als Deadlockopfer ausgewählt.");
+        assert!(deadlock.is_retryable(&retryable, false, false));
+        let missing = server(208, "Invalid object name 'dbo.busy_orders'.");
+        assert!(!missing.is_retryable(&retryable, true, true));
+        assert!(!server(1205, "deadlock").is_retryable(&[], true, true));
+        let other = SqlError::Other("connection reset".to_string());
+        assert!(!other.is_retryable(&retryable, true, true));
+        assert_eq!(
+            server(1222, "Lock request time out period exceeded.").to_string(),
+            "Msg 1222, Level 13, State 51: Lock request time out period exceeded."
+        );
+    }
+
+    #[test]
+    fn timeouts_and_connection_errors_can_be_turned_off() {
+        let timeout = SqlError::Timeout { timeout_ms: 1000 };
+        assert!(!timeout.is_retryable(&[], false, true));
+        assert!(timeout.is_retryable(&[], true, false));
+        let io = SqlError::Io("An existing connection was forcibly closed".to_string());
+        assert!(!io.is_retryable(&[], true, false));
+        assert!(io.is_retryable(&[], false, true));
+        assert!(timeout.needs_new_connection() && io.needs_new_connection());
+        assert!(!SqlError::Other("x".to_string()).needs_new_connection());
+
+        let failed = io.context("Connection failed");
+        assert!(matches!(&failed, SqlError::Io(m) if m.starts_with("Connection failed: An existing")));
+        assert!(failed.needs_new_connection());
+    }
+
+    #[test]
+    fn classifies_driver_errors() {
+        use tiberius::error::{Error, IoErrorKind};
+
+        let io: SqlError = Error::Io {
+            kind: IoErrorKind::ConnectionReset,
+            message: "connection reset by peer".to_string(),
+        }
+        .into();
+        assert!(matches!(&io, SqlError::Io(m) if m.contains("connection reset by peer")));
+        assert_eq!(io.number(), None);
+
+        let protocol: SqlError = Error::Protocol("unexpected token".into()).into();
+        assert!(matches!(&protocol, SqlError::Other(m) if m.contains("unexpected token")));
+
+        let tls: SqlError = Error::Tls("certificate not trusted".to_string()).into();
+        assert!(matches!(tls, SqlError::Other(_)));
+    }
+}
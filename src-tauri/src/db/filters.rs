@@ -0,0 +1,188 @@
+use crate::models::types::{
+    IndexInfo, MaintenanceOptions, OptionOverride, PatternFilter, StatisticsInfo,
+};
+use std::borrow::Cow;
+
+/// Case-insensitive match of a `LIKE`-style pattern: `%` matches any run of
+/// characters and `_` exactly one. There is no escape character.
+pub fn like_match(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.to_lowercase().chars().collect();
+    let t: Vec<char> = text.to_lowercase().chars().collect();
+    let (mut pi, mut ti) = (0, 0);
+    // Position of the last `%` and the text position it is currently matched up to,
+    // so a mismatch can backtrack by letting that `%` swallow one more character.
+    let mut backtrack: Option<(usize, usize)> = None;
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == '_' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if pi < p.len() && p[pi] == '%' {
+            backtrack = Some((pi, ti));
+            pi += 1;
+        } else if let Some((star, matched)) = backtrack {
+            pi = star + 1;
+            ti = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|&c| c == '%')
+}
+
+/// Non-blank patterns of a list, as typed one per line in the options panel.
+fn patterns(list: &[String]) -> impl Iterator<Item = &str> {
+    list.iter().map(|p| p.trim()).filter(|p| !p.is_empty())
+}
+
+/// Why `filter` rejects an object, or `None` when it passes. `matches` tests one
+/// pattern against the object; `kind` names the level in the reason.
+fn rejection(filter: &PatternFilter, kind: &str, matches: impl Fn(&str) -> bool) -> Option<String> {
+    if let Some(pattern) = patterns(&filter.exclude).find(|p| matches(p)) {
+        return Some(format!("Excluded by {} pattern '{}'", kind, pattern));
+    }
+    let mut include = patterns(&filter.include).peekable();
+    if include.peek().is_some() && !include.any(&matches) {
+        return Some(format!("Not matched by any {} include pattern", kind));
+    }
+    None
+}
+
+pub fn database_rejection(filter: &PatternFilter, db_name: &str) -> Option<String> {
+    rejection(filter, "database", |p| like_match(p, db_name))
+}
+
+/// Table patterns containing a `.` are matched against `schema.table`, others
+/// against the table name alone.
+fn table_matches(pattern: &str, schema: &str, table: &str) -> bool {
+    if pattern.contains('.') {
+        like_match(pattern, &format!("{}.{}", schema, table))
+    } else {
+        like_match(pattern, table)
+    }
+}
+
+fn table_rejection(options: &MaintenanceOptions, schema: &str, table: &str) -> Option<String> {
+    rejection(&options.schema_filter, "schema", |p| like_match(p, schema))
+        .or_else(|| rejection(&options.table_filter, "table", |p| table_matches(p, schema, table)))
+}
+
+/// Why an index is excluded by the schema, table and index patterns.
+pub fn index_rejection(options: &MaintenanceOptions, index: &IndexInfo) -> Option<String> {
+    table_rejection(options, &index.schema_name, &index.table_name)
+        .or_else(|| rejection(&options.index_filter, "index", |p| like_match(p, &index.index_name)))
+}
+
+/// Column statistics follow their table's patterns; index statistics also
+/// follow the index patterns.
+pub fn statistics_rejection(options: &MaintenanceOptions, stat: &StatisticsInfo) -> Option<String> {
+    table_rejection(options, &stat.schema_name, &stat.table_name).or_else(|| {
+        stat.is_index_statistics
+            .then(|| rejection(&options.index_filter, "index", |p| like_match(p, &stat.stats_name)))
+            .flatten()
+    })
+}
+
+/// Marks the indexes the patterns exclude; they are reported but never touched.
+pub fn apply_index_policy(indexes: &mut [IndexInfo], options: &MaintenanceOptions) {
+    for index in indexes {
+        index.excluded_by = index_rejection(options, index);
+    }
+}
+
+fn with_overrides(
+    options: &MaintenanceOptions,
+    applies: impl Fn(&OptionOverride) -> bool,
+) -> Cow<'_, MaintenanceOptions> {
+    let mut matching = options.overrides.iter().filter(|o| applies(o)).peekable();
+    if matching.peek().is_none() {
+        return Cow::Borrowed(options);
+    }
+    let mut merged = options.clone();
+    for o in matching {
+        o.apply_to(&mut merged);
+    }
+    Cow::Owned(merged)
+}
+
+/// Run options for a database: the run-wide options with the database-level
+/// overrides matching `db_name` applied.
+pub fn database_options<'a>(options: &'a MaintenanceOptions, db_name: &str) -> Cow<'a, MaintenanceOptions> {
+    with_overrides(options, |o| o.table.trim().is_empty() && like_match(o.database.trim(), db_name))
+}
+
+/// Options for the indexes of one table, on top of [`database_options`].
+pub fn table_options<'a>(
+    db_options: &'a MaintenanceOptions,
+    db_name: &str,
+    schema: &str,
+    table: &str,
+) -> Cow<'a, MaintenanceOptions> {
+    with_overrides(db_options, |o| {
+        !o.table.trim().is_empty()
+            && like_match(o.database.trim(), db_name)
+            && table_matches(o.table.trim(), schema, table)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn like_wildcards() {
+        assert!(like_match("IX_tmp_%", "ix_TMP_orders"));
+        assert!(like_match("audit", "Audit"));
+        assert!(like_match("%_log", "event_log"));
+        assert!(like_match("a%b%c", "aXXbYYc"));
+        assert!(like_match("%", ""));
+        assert!(!like_match("IX_tmp_%", "IX_orders"));
+        assert!(!like_match("_", ""));
+        assert!(!like_match("a%b", "aXXbc"));
+    }
+
+    #[test]
+    fn exclude_wins_over_include() {
+        let filter = PatternFilter {
+            include: vec!["Sales%".to_string(), " ".to_string()],
+            exclude: vec!["%_archive".to_string()],
+        };
+        assert_eq!(database_rejection(&filter, "SalesEU"), None);
+        assert_eq!(
+            database_rejection(&filter, "Sales_archive"),
+            Some("Excluded by database pattern '%_archive'".to_string())
+        );
+        assert_eq!(
+            database_rejection(&filter, "HR"),
+            Some("Not matched by any database include pattern".to_string())
+        );
+        assert_eq!(database_rejection(&PatternFilter::default(), "HR"), None);
+    }
+
+    #[test]
+    fn overrides_merge_database_then_table() {
+        let options = MaintenanceOptions {
+            overrides: vec![
+                OptionOverride {
+                    database: "Sales".to_string(),
+                    table: "dbo.Orders".to_string(),
+                    maxdop: Some(8),
+                    ..Default::default()
+                },
+                OptionOverride {
+                    database: "Sales".to_string(),
+                    rebuild_online: Some(false),
+                    maxdop: Some(2),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let db = database_options(&options, "sales");
+        assert!(!db.rebuild_online);
+        assert_eq!(db.maxdop, 2);
+        assert_eq!(table_options(&db, "sales", "dbo", "Orders").maxdop, 8);
+        assert_eq!(table_options(&db, "sales", "dbo", "Lines").maxdop, 2);
+        assert!(matches!(database_options(&options, "HR"), Cow::Borrowed(_)));
+    }
+}
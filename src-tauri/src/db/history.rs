@@ -1,5 +1,18 @@
-use crate::models::types::{DatabaseResult, MaintenanceSummary, RunRecord};
+use crate::models::types::{
+    DatabaseResult, IndexOutcome, MaintenanceAction, MaintenanceSummary, RunRecord,
+};
 use rusqlite::{params, Connection, Result};
+use std::collections::HashMap;
+
+/// Sequential schema migrations applied after the legacy column check below.
+/// `PRAGMA user_version` stores how many of these have run; append only.
+const MIGRATIONS: &[&str] = &[
+    // 1 — page-count filters used for the run
+    "ALTER TABLE run_history ADD COLUMN min_page_count INTEGER NOT NULL DEFAULT 100;
+     ALTER TABLE run_history ADD COLUMN max_page_count INTEGER NOT NULL DEFAULT 0;",
+    // 2 — indexes deferred because the maintenance window closed
+    "ALTER TABLE run_history ADD COLUMN total_indexes_deferred INTEGER NOT NULL DEFAULT 0;",
+];
 
 pub fn create_tables(conn: &Connection) -> Result<()> {
     conn.execute_batch(
@@ -21,9 +34,7 @@ pub fn create_tables(conn: &Connection) -> Result<()> {
         );",
     )?;
 
-    // Per-column migration: each new column gets its own pragma_table_info check.
-    // If more columns are added in the future, consider replacing this with a
-    // schema_version table and sequential numbered migrations.
+    // Legacy per-column migration from before numbered migrations existed.
     let has_col: bool = conn
         .prepare("SELECT COUNT(*) FROM pragma_table_info('run_history') WHERE name='database_results'")?
         .query_row([], |row| row.get::<_, i64>(0))
@@ -34,6 +45,17 @@ pub fn create_tables(conn: &Connection) -> Result<()> {
         )?;
     }
 
+    run_migrations(conn)
+}
+
+fn run_migrations(conn: &Connection) -> Result<()> {
+    let applied: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    for (idx, sql) in MIGRATIONS.iter().enumerate().skip(applied.max(0) as usize) {
+        let tx = conn.unchecked_transaction()?;
+        tx.execute_batch(sql)?;
+        tx.execute_batch(&format!("PRAGMA user_version = {};", idx + 1))?;
+        tx.commit()?;
+    }
     Ok(())
 }
 
@@ -53,8 +75,9 @@ pub fn insert_run(
             profile_id, profile_name, server, started_at, finished_at,
             databases_processed, databases_failed, databases_skipped,
             total_indexes_rebuilt, total_indexes_reorganized, total_indexes_skipped,
-            total_duration_secs, database_results
-        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+            total_duration_secs, database_results, min_page_count, max_page_count,
+            total_indexes_deferred
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
         params![
             profile_id,
             profile_name,
@@ -69,6 +92,9 @@ pub fn insert_run(
             summary.total_indexes_skipped,
             summary.total_duration_secs,
             db_results_json,
+            summary.min_page_count,
+            summary.max_page_count,
+            summary.total_indexes_deferred,
         ],
     )?;
     Ok(())
@@ -84,7 +110,8 @@ pub fn get_runs(
             "SELECT id, profile_id, profile_name, server, started_at, finished_at,
                 databases_processed, databases_failed, databases_skipped,
                 total_indexes_rebuilt, total_indexes_reorganized, total_indexes_skipped,
-                total_duration_secs, database_results
+                total_duration_secs, database_results, min_page_count, max_page_count,
+                total_indexes_deferred
              FROM run_history WHERE profile_id = ?1 ORDER BY id DESC LIMIT ?2",
             Some(id),
         ),
@@ -92,7 +119,8 @@ pub fn get_runs(
             "SELECT id, profile_id, profile_name, server, started_at, finished_at,
                 databases_processed, databases_failed, databases_skipped,
                 total_indexes_rebuilt, total_indexes_reorganized, total_indexes_skipped,
-                total_duration_secs, database_results
+                total_duration_secs, database_results, min_page_count, max_page_count,
+                total_indexes_deferred
              FROM run_history ORDER BY id DESC LIMIT ?1",
             None,
         ),
@@ -130,10 +158,95 @@ fn row_to_record(row: &rusqlite::Row) -> Result<RunRecord> {
         total_indexes_reorganized: row.get::<_, i64>(10)? as u32,
         total_indexes_skipped: row.get::<_, i64>(11)? as u32,
         total_duration_secs: row.get(12)?,
+        min_page_count: row.get(14)?,
+        max_page_count: row.get(15)?,
+        total_indexes_deferred: row.get::<_, i64>(16)? as u32,
         database_results,
     })
 }
 
+/// Key of [`index_last_maintained`] for one index (or partition).
+pub fn index_key(
+    database: &str,
+    schema: &str,
+    table: &str,
+    index: &str,
+    partition: Option<i32>,
+) -> String {
+    let partition = partition.map(|n| n.to_string()).unwrap_or_default();
+    format!("{database}.{schema}.{table}.{index}:{partition}")
+}
+
+/// Key of [`index_duration_estimates`] for one index operation.
+pub fn duration_key(
+    database: &str,
+    schema: &str,
+    table: &str,
+    index: &str,
+    partition: Option<i32>,
+    action: &MaintenanceAction,
+) -> String {
+    format!("{}:{action:?}", index_key(database, schema, table, index, partition))
+}
+
+/// Duration of the latest completed run of each index operation across the
+/// profile's recent runs, used to predict whether it fits a maintenance window.
+pub fn index_duration_estimates(
+    conn: &Connection,
+    profile_id: &str,
+    runs: u32,
+) -> Result<HashMap<String, f64>> {
+    let mut estimates = HashMap::new();
+    // Newest first, so the first duration seen for a key is the latest one.
+    for run in get_runs(conn, Some(profile_id), runs)? {
+        for db in &run.database_results {
+            for r in &db.index_results {
+                if !r.success || r.outcome != IndexOutcome::Completed {
+                    continue;
+                }
+                let key = duration_key(
+                    &db.database_name,
+                    &r.schema_name,
+                    &r.table_name,
+                    &r.index_name,
+                    r.partition_number,
+                    &r.action,
+                );
+                estimates.entry(key).or_insert(r.duration_secs);
+            }
+        }
+    }
+    Ok(estimates)
+}
+
+/// Start time (RFC 3339, UTC) of the latest run that completed an operation on
+/// each index across the profile's recent runs.
+pub fn index_last_maintained(
+    conn: &Connection,
+    profile_id: &str,
+    runs: u32,
+) -> Result<HashMap<String, String>> {
+    let mut last_maintained = HashMap::new();
+    for run in get_runs(conn, Some(profile_id), runs)? {
+        for db in &run.database_results {
+            for r in &db.index_results {
+                if !r.success || r.outcome != IndexOutcome::Completed {
+                    continue;
+                }
+                let key = index_key(
+                    &db.database_name,
+                    &r.schema_name,
+                    &r.table_name,
+                    &r.index_name,
+                    r.partition_number,
+                );
+                last_maintained.entry(key).or_insert_with(|| run.started_at.clone());
+            }
+        }
+    }
+    Ok(last_maintained)
+}
+
 pub fn delete_runs(conn: &Connection, profile_id: Option<&str>) -> Result<()> {
     match profile_id {
         Some(id) => {
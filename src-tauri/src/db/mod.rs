@@ -1,3 +1,8 @@
+pub mod azure_ad;
 pub mod connection;
+pub mod error;
+pub mod filters;
 pub mod history;
 pub mod queries;
+pub mod script;
+pub mod sql_browser;
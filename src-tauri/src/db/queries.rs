@@ -1,4 +1,11 @@
-use crate::models::types::IndexInfo;
+use crate::db::error::SqlError;
+use crate::db::filters::{apply_index_policy, database_rejection};
+use crate::models::types::{
+    AbortAfterWait, AgQueues, BlockingSession, BlockingSnapshot, ColumnstoreStats, DataCompression,
+    IndexInfo, IndexKind, IndexProgress, LogSpaceUsage, MaintenanceOptions, PatternFilter,
+    ReplicaRole, ServerInfo, StatisticsInfo, StatisticsSampling, UserDatabase,
+};
+use std::collections::HashMap;
 use tiberius::{Client, Row};
 use tokio::net::TcpStream;
 use tokio_util::compat::Compat;
@@ -12,70 +19,523 @@ pub const GET_USER_DATABASES: &str = "
     ORDER BY name;
 ";
 
+/// Role of the local replica for every database in an Availability Group
+/// (`role`: 0 = resolving, 1 = primary, 2 = secondary).
+pub const GET_REPLICA_ROLES: &str = "
+    SELECT
+      d.name AS DatabaseName,
+      CAST(ars.role AS int) AS ReplicaRole,
+      ag.name AS AvailabilityGroup
+    FROM sys.databases AS d
+    INNER JOIN sys.dm_hadr_availability_replica_states AS ars
+      ON ars.replica_id = d.replica_id AND ars.is_local = 1
+    INNER JOIN sys.availability_groups AS ag ON ag.group_id = ars.group_id;
+";
+
+/// Queues of the current database's secondary replicas; NULLs outside an
+/// Availability Group or on a secondary.
+pub const GET_AG_QUEUES: &str = "
+    SELECT
+      CAST(MAX(drs.log_send_queue_size) / 1024.0 AS float) AS LogSendQueueMb,
+      CAST(MAX(drs.redo_queue_size) / 1024.0 AS float) AS RedoQueueMb
+    FROM sys.dm_hadr_database_replica_states AS drs
+    WHERE drs.database_id = DB_ID() AND drs.is_local = 0;
+";
+
 pub const GET_FRAGMENTED_INDEXES: &str = "
     SELECT
       s.name AS SchemaName,
       t.name AS TableName,
       i.name AS IndexName,
       CAST(ips.avg_fragmentation_in_percent AS float) AS FragmentationPercent,
-      CAST(ips.page_count AS bigint) AS PageCount
-    FROM sys.dm_db_index_physical_stats(DB_ID(), NULL, NULL, NULL, 'LIMITED') AS ips
+      CAST(ips.page_count AS bigint) AS PageCount,
+      CAST(ips.avg_page_space_used_in_percent AS float) AS PageDensityPercent,
+      CAST(ips.record_count AS bigint) AS RecordCount,
+      CAST(ips.ghost_record_count AS bigint) AS GhostRecordCount,
+      ips.partition_number AS PartitionNumber,
+      (SELECT COUNT(*) FROM sys.partitions AS p
+        WHERE p.object_id = i.object_id AND p.index_id = i.index_id) AS PartitionCount,
+      CAST(ips.forwarded_record_count AS bigint) AS ForwardedRecordCount,
+      CAST(i.type AS int) AS IndexType,
+      -- LOB columns block online rebuilds. Clustered indexes and heaps carry
+      -- every column of the table; other indexes only their key/included columns.
+      CAST(CASE WHEN EXISTS (
+        SELECT 1 FROM sys.columns AS c
+        WHERE c.object_id = i.object_id
+          AND c.system_type_id IN (34, 35, 99)  -- image, text, ntext
+          AND (i.index_id IN (0, 1) OR EXISTS (
+            SELECT 1 FROM sys.index_columns AS ic
+            WHERE ic.object_id = i.object_id AND ic.index_id = i.index_id
+              AND ic.column_id = c.column_id))
+      ) THEN 1 ELSE 0 END AS bit) AS HasLegacyLob,
+      CAST(CASE WHEN EXISTS (
+        SELECT 1 FROM sys.columns AS c
+        WHERE c.object_id = i.object_id
+          AND (c.max_length = -1 OR c.system_type_id = 241)  -- (n)varchar(max), varbinary(max), xml
+          AND (i.index_id IN (0, 1) OR EXISTS (
+            SELECT 1 FROM sys.index_columns AS ic
+            WHERE ic.object_id = i.object_id AND ic.index_id = i.index_id
+              AND ic.column_id = c.column_id))
+      ) THEN 1 ELSE 0 END AS bit) AS HasMaxLob
+    FROM sys.dm_db_index_physical_stats(DB_ID(), NULL, NULL, NULL, @P1) AS ips
     INNER JOIN sys.indexes AS i ON ips.object_id = i.object_id AND ips.index_id = i.index_id
     INNER JOIN sys.tables AS t ON i.object_id = t.object_id
     INNER JOIN sys.schemas AS s ON t.schema_id = s.schema_id
-    WHERE ips.index_id > 0
-      AND ips.page_count > 100  -- skip tiny indexes; maintenance overhead isn't worth it below ~800 KB
+    WHERE (ips.index_id > 0 OR @P4 = 1)  -- heaps (index_id 0) are opt-in
+      AND ips.index_level = 0  -- DETAILED also returns non-leaf levels
+      AND ips.alloc_unit_type_desc = 'IN_ROW_DATA'
+      AND i.type NOT IN (5, 6)  -- columnstore indexes are read from their rowgroup stats
+      AND ips.page_count >= @P2
+      AND (@P3 = 0 OR ips.page_count <= @P3)
       AND t.is_ms_shipped = 0
-      AND i.name IS NOT NULL
+      AND (i.name IS NOT NULL OR i.index_id = 0)
     ORDER BY ips.avg_fragmentation_in_percent DESC;
 ";
 
+/// `sys.dm_db_column_store_row_group_physical_stats` only exists on SQL Server
+/// 2016 and later; older servers skip the columnstore query entirely.
+pub const HAS_COLUMNSTORE_ROW_GROUP_STATS: &str = "
+    SELECT CASE WHEN OBJECT_ID('sys.dm_db_column_store_row_group_physical_stats') IS NULL
+      THEN 0 ELSE 1 END;
+";
+
+pub const GET_COLUMNSTORE_INDEXES: &str = "
+    SELECT
+      s.name AS SchemaName,
+      t.name AS TableName,
+      i.name AS IndexName,
+      CAST(ISNULL(100.0 * SUM(rg.deleted_rows) / NULLIF(SUM(rg.total_rows), 0), 0) AS float)
+        AS DeletedRowsPercent,
+      CAST(SUM(rg.size_in_bytes) / 8192 AS bigint) AS PageCount,
+      rg.partition_number AS PartitionNumber,
+      (SELECT COUNT(*) FROM sys.partitions AS p
+        WHERE p.object_id = i.object_id AND p.index_id = i.index_id) AS PartitionCount,
+      CAST(i.type AS int) AS IndexType,
+      CAST(SUM(rg.total_rows) AS bigint) AS TotalRows,
+      CAST(SUM(rg.deleted_rows) AS bigint) AS DeletedRows,
+      SUM(CASE WHEN rg.state_desc = 'OPEN' THEN 1 ELSE 0 END) AS OpenRowGroups,
+      SUM(CASE WHEN rg.state_desc = 'CLOSED' THEN 1 ELSE 0 END) AS ClosedRowGroups,
+      SUM(CASE WHEN rg.state_desc = 'COMPRESSED' THEN 1 ELSE 0 END) AS CompressedRowGroups
+    FROM sys.dm_db_column_store_row_group_physical_stats AS rg
+    INNER JOIN sys.indexes AS i ON rg.object_id = i.object_id AND rg.index_id = i.index_id
+    INNER JOIN sys.tables AS t ON i.object_id = t.object_id
+    INNER JOIN sys.schemas AS s ON t.schema_id = s.schema_id
+    WHERE t.is_ms_shipped = 0
+      AND i.name IS NOT NULL
+    GROUP BY s.name, t.name, i.name, i.object_id, i.index_id, i.type, rg.partition_number
+    HAVING SUM(rg.size_in_bytes) / 8192 >= @P1
+      AND (@P2 = 0 OR SUM(rg.size_in_bytes) / 8192 <= @P2);
+";
+
+/// Resumable rebuilds left paused by an earlier run (or by MAX_DURATION).
+/// `sys.index_resumable_operations` exists on SQL Server 2017 and later.
+pub const GET_PAUSED_RESUMABLE_OPERATIONS: &str = "
+    SELECT
+      s.name AS SchemaName,
+      t.name AS TableName,
+      i.name AS IndexName,
+      CAST(iro.percent_complete AS float) AS PercentComplete,
+      CAST(iro.page_count AS bigint) AS PageCount
+    FROM sys.index_resumable_operations AS iro
+    INNER JOIN sys.indexes AS i ON iro.object_id = i.object_id AND iro.index_id = i.index_id
+    INNER JOIN sys.tables AS t ON i.object_id = t.object_id
+    INNER JOIN sys.schemas AS s ON t.schema_id = s.schema_id
+    WHERE iro.state_desc = 'PAUSED'
+    ORDER BY iro.last_pause_time;
+";
+
+/// Statistics of user tables with their modification counters. Columnstore
+/// index statistics are left out — the optimizer does not use them.
+pub const GET_STATISTICS: &str = "
+    SELECT
+      sch.name AS SchemaName,
+      t.name AS TableName,
+      st.name AS StatsName,
+      CAST(CASE WHEN i.index_id IS NULL THEN 0 ELSE 1 END AS bit) AS IsIndexStatistics,
+      CAST(sp.rows AS bigint) AS Rows,
+      CAST(sp.modification_counter AS bigint) AS ModificationCounter,
+      CONVERT(nvarchar(19), sp.last_updated, 120) AS LastUpdated,
+      DATEDIFF(DAY, sp.last_updated, GETDATE()) AS DaysSinceUpdate
+    FROM sys.stats AS st
+    INNER JOIN sys.tables AS t ON st.object_id = t.object_id
+    INNER JOIN sys.schemas AS sch ON t.schema_id = sch.schema_id
+    LEFT JOIN sys.indexes AS i ON st.object_id = i.object_id AND st.stats_id = i.index_id
+    CROSS APPLY sys.dm_db_stats_properties(st.object_id, st.stats_id) AS sp
+    WHERE t.is_ms_shipped = 0
+      AND (i.index_id IS NOT NULL OR @P1 = 1)
+      AND (i.type IS NULL OR i.type NOT IN (5, 6))
+    ORDER BY sp.modification_counter DESC;
+";
+
+/// Reads per index since the last restart. Heaps have no index name and come
+/// back with a NULL `IndexName`.
+pub const GET_INDEX_USAGE: &str = "
+    SELECT
+      s.name AS SchemaName,
+      t.name AS TableName,
+      i.name AS IndexName,
+      CAST(us.user_seeks + us.user_scans + us.user_lookups AS bigint) AS Reads
+    FROM sys.dm_db_index_usage_stats AS us
+    INNER JOIN sys.indexes AS i ON us.object_id = i.object_id AND us.index_id = i.index_id
+    INNER JOIN sys.tables AS t ON i.object_id = t.object_id
+    INNER JOIN sys.schemas AS s ON t.schema_id = s.schema_id
+    WHERE us.database_id = DB_ID();
+";
+
+pub const GET_LOG_SPACE_USAGE: &str = "
+    SELECT
+      CAST(lsu.used_log_space_in_percent AS float) AS UsedPercent,
+      CAST(lsu.total_log_size_in_bytes / 1048576.0 AS float) AS TotalMb,
+      d.log_reuse_wait_desc AS LogReuseWait
+    FROM sys.dm_db_log_space_usage AS lsu
+    INNER JOIN sys.databases AS d ON d.database_id = lsu.database_id;
+";
+
+pub const GET_SESSION_ID: &str = "SELECT CAST(@@SPID AS int) AS SessionId;";
+
+/// The session that has waited longest on session @P1. Parallel workers of
+/// @P1's own request are not blocking.
+pub const GET_BLOCKED_SESSION: &str = "
+    SELECT TOP (1)
+      CAST(wt.session_id AS int) AS SessionId,
+      CAST(wt.wait_duration_ms / 1000.0 AS float) AS WaitSecs,
+      wt.wait_type AS WaitType,
+      s.login_name AS LoginName,
+      s.host_name AS HostName,
+      s.program_name AS ProgramName
+    FROM sys.dm_os_waiting_tasks AS wt
+    INNER JOIN sys.dm_exec_sessions AS s ON s.session_id = wt.session_id
+    WHERE wt.blocking_session_id = @P1 AND wt.session_id <> @P1
+    ORDER BY wt.wait_duration_ms DESC;
+";
+
+/// The session blocking the current request of session @P1, with how long the
+/// request has waited on it. Negative IDs (orphaned distributed transactions,
+/// deferred recovery, latch owners) do not name a session and are ignored.
+pub const GET_BLOCKING_SESSION: &str = "
+    SELECT TOP (1)
+      CAST(r.blocking_session_id AS int) AS SessionId,
+      CAST(r.wait_time / 1000.0 AS float) AS WaitSecs,
+      r.wait_type AS WaitType,
+      s.login_name AS LoginName,
+      s.host_name AS HostName,
+      s.program_name AS ProgramName
+    FROM sys.dm_exec_requests AS r
+    LEFT JOIN sys.dm_exec_sessions AS s ON s.session_id = r.blocking_session_id
+    WHERE r.session_id = @P1 AND r.blocking_session_id > 0 AND r.blocking_session_id <> @P1;
+";
+
+/// The current request of session @P1.
+pub const GET_REQUEST_PROGRESS: &str = "
+    SELECT
+      CAST(r.percent_complete AS float) AS PercentComplete,
+      CAST(r.estimated_completion_time / 1000.0 AS float) AS EstimatedRemainingSecs,
+      r.wait_type AS WaitType,
+      CAST(r.wait_time / 1000.0 AS float) AS WaitSecs,
+      CAST(r.cpu_time AS bigint) AS CpuTimeMs,
+      CAST(r.logical_reads AS bigint) AS LogicalReads,
+      CAST(r.reads AS bigint) AS Reads,
+      CAST(r.writes AS bigint) AS Writes,
+      CAST(r.total_elapsed_time / 1000.0 AS float) AS ElapsedSecs
+    FROM sys.dm_exec_requests AS r
+    WHERE r.session_id = @P1;
+";
+
+pub const GET_SERVER_INFO: &str = "
+    SELECT
+      CAST(SERVERPROPERTY('Edition') AS nvarchar(128)) AS Edition,
+      CAST(SERVERPROPERTY('EngineEdition') AS int) AS EngineEdition,
+      CAST(SERVERPROPERTY('ProductVersion') AS nvarchar(128)) AS ProductVersion;
+";
+
+pub const GET_SESSION_AUTH: &str = "
+    SELECT
+      CAST(c.auth_scheme AS nvarchar(40)) AS AuthScheme,
+      SUSER_SNAME() AS LoginName
+    FROM sys.dm_exec_connections AS c
+    WHERE c.session_id = @@SPID;
+";
+
+pub const GET_LOGIN_NAME: &str = "SELECT SUSER_SNAME() AS LoginName;";
+
 pub const FREE_PROC_CACHE: &str = "DBCC FREEPROCCACHE;";
 
 /// Escapes a SQL Server identifier for use inside `[...]` brackets.
 /// A `]` inside an identifier is escaped as `]]`.
-fn bracket_escape(s: &str) -> String {
+pub(crate) fn bracket_escape(s: &str) -> String {
     s.replace(']', "]]")
 }
 
-pub fn rebuild_index_sql(schema: &str, table: &str, index: &str, online: bool) -> String {
+/// Escapes text for use inside an `N'...'` string literal.
+/// A `'` inside the text is escaped as `''`.
+pub(crate) fn quote_escape(s: &str) -> String {
+    s.replace('\'', "''")
+}
+
+/// ` PARTITION = n` clause, or an empty string for whole-index operations.
+fn partition_clause(partition: Option<i32>) -> String {
+    partition.map(|n| format!(" PARTITION = {}", n)).unwrap_or_default()
+}
+
+/// `WITH (...)` settings of an index rebuild.
+#[derive(Debug, Clone, Default)]
+pub struct RebuildOptions {
+    pub online: bool,
+    /// `RESUMABLE = ON`; only valid together with `online`.
+    pub resumable: bool,
+    /// `MAX_DURATION` of a resumable rebuild in minutes (0 = no limit).
+    pub max_duration_minutes: u32,
+    /// 0 = server default.
+    pub maxdop: u32,
+    /// Not supported by resumable rebuilds, so omitted for those.
+    pub sort_in_tempdb: bool,
+    /// 0 = keep the index's fill factor.
+    pub fill_factor: u32,
+    pub pad_index: bool,
+    pub data_compression: DataCompression,
+    /// Only applies to online rebuilds.
+    pub low_priority: Option<LowPriorityWait>,
+}
+
+/// `WAIT_AT_LOW_PRIORITY` settings for online operations.
+#[derive(Debug, Clone)]
+pub struct LowPriorityWait {
+    pub max_duration_minutes: u32,
+    pub abort_after_wait: AbortAfterWait,
+}
+
+impl LowPriorityWait {
+    fn as_sql(&self) -> String {
+        format!(
+            "WAIT_AT_LOW_PRIORITY (MAX_DURATION = {} MINUTES, ABORT_AFTER_WAIT = {})",
+            self.max_duration_minutes,
+            self.abort_after_wait.as_sql()
+        )
+    }
+}
+
+impl RebuildOptions {
+    /// Single-partition rebuilds reject FILLFACTOR and PAD_INDEX, so they are
+    /// dropped when `partition` is set.
+    fn with_clause(&self, partition: Option<i32>) -> String {
+        let mut settings = Vec::new();
+        if partition.is_none() {
+            if self.fill_factor > 0 {
+                settings.push(format!("FILLFACTOR = {}", self.fill_factor));
+            }
+            if self.pad_index {
+                settings.push("PAD_INDEX = ON".to_string());
+            }
+        }
+        if self.sort_in_tempdb && !self.resumable {
+            settings.push("SORT_IN_TEMPDB = ON".to_string());
+        }
+        match (&self.low_priority, self.online) {
+            (Some(wait), true) => settings.push(format!("ONLINE = ON ({})", wait.as_sql())),
+            (None, true) => settings.push("ONLINE = ON".to_string()),
+            (_, false) => settings.push("ONLINE = OFF".to_string()),
+        }
+        if self.resumable {
+            settings.push("RESUMABLE = ON".to_string());
+            if self.max_duration_minutes > 0 {
+                settings.push(format!("MAX_DURATION = {} MINUTES", self.max_duration_minutes));
+            }
+        }
+        if self.maxdop > 0 {
+            settings.push(format!("MAXDOP = {}", self.maxdop));
+        }
+        if let Some(compression) = self.data_compression.as_sql() {
+            settings.push(format!("DATA_COMPRESSION = {}", compression));
+        }
+        format!(" WITH ({})", settings.join(", "))
+    }
+}
+
+pub fn rebuild_index_sql(
+    schema: &str,
+    table: &str,
+    index: &str,
+    partition: Option<i32>,
+    options: &RebuildOptions,
+) -> String {
+    format!(
+        "ALTER INDEX [{}] ON [{}].[{}] REBUILD{}{};",
+        bracket_escape(index),
+        bracket_escape(schema),
+        bracket_escape(table),
+        partition_clause(partition),
+        options.with_clause(partition),
+    )
+}
+
+/// `SET` statements for a maintenance connection, or `None` when every session
+/// setting is left at the server default.
+pub fn session_settings_sql(options: &MaintenanceOptions) -> Option<String> {
+    let mut settings = Vec::new();
+    if options.lock_timeout_ms > 0 {
+        settings.push(format!("SET LOCK_TIMEOUT {};", options.lock_timeout_ms));
+    }
+    if options.deadlock_priority_low {
+        settings.push("SET DEADLOCK_PRIORITY LOW;".to_string());
+    }
+    (!settings.is_empty()).then(|| settings.join(" "))
+}
+
+/// Pauses a running resumable rebuild. Issued from a second connection, since
+/// the session running the rebuild is blocked on it.
+pub fn pause_index_sql(schema: &str, table: &str, index: &str) -> String {
     format!(
-        "ALTER INDEX [{}] ON [{}].[{}] REBUILD WITH (ONLINE = {});",
+        "ALTER INDEX [{}] ON [{}].[{}] PAUSE;",
         bracket_escape(index),
         bracket_escape(schema),
         bracket_escape(table),
-        if online { "ON" } else { "OFF" }
     )
 }
 
-pub fn reorganize_index_sql(schema: &str, table: &str, index: &str) -> String {
+pub fn resume_index_sql(
+    schema: &str,
+    table: &str,
+    index: &str,
+    max_duration_minutes: u32,
+    low_priority: Option<&LowPriorityWait>,
+) -> String {
+    let mut settings = Vec::new();
+    if max_duration_minutes > 0 {
+        settings.push(format!("MAX_DURATION = {} MINUTES", max_duration_minutes));
+    }
+    if let Some(wait) = low_priority {
+        settings.push(wait.as_sql());
+    }
+    let with = if settings.is_empty() {
+        String::new()
+    } else {
+        format!(" WITH ({})", settings.join(", "))
+    };
     format!(
-        "ALTER INDEX [{}] ON [{}].[{}] REORGANIZE;",
+        "ALTER INDEX [{}] ON [{}].[{}] RESUME{};",
         bracket_escape(index),
         bracket_escape(schema),
         bracket_escape(table),
+        with,
     )
 }
 
-pub fn update_statistics_sql(schema: &str, table: &str, index: &str) -> String {
+pub fn reorganize_index_sql(
+    schema: &str,
+    table: &str,
+    index: &str,
+    partition: Option<i32>,
+) -> String {
     format!(
-        "UPDATE STATISTICS [{}].[{}] [{}] WITH FULLSCAN;",
+        "ALTER INDEX [{}] ON [{}].[{}] REORGANIZE{};",
+        bracket_escape(index),
         bracket_escape(schema),
         bracket_escape(table),
+        partition_clause(partition),
+    )
+}
+
+/// Columnstore REORGANIZE that also compresses OPEN delta rowgroups instead of
+/// waiting for them to fill up.
+pub fn reorganize_columnstore_sql(
+    schema: &str,
+    table: &str,
+    index: &str,
+    partition: Option<i32>,
+) -> String {
+    format!(
+        "ALTER INDEX [{}] ON [{}].[{}] REORGANIZE{} WITH (COMPRESS_ALL_ROW_GROUPS = ON);",
         bracket_escape(index),
+        bracket_escape(schema),
+        bracket_escape(table),
+        partition_clause(partition),
+    )
+}
+
+/// Rebuilds a heap, removing forwarded records. Rebuilding the whole heap also
+/// rebuilds its nonclustered indexes, since their row locators change.
+pub fn rebuild_heap_sql(
+    schema: &str,
+    table: &str,
+    partition: Option<i32>,
+    options: &RebuildOptions,
+) -> String {
+    format!(
+        "ALTER TABLE [{}].[{}] REBUILD{}{};",
+        bracket_escape(schema),
+        bracket_escape(table),
+        partition_clause(partition),
+        options.with_clause(partition),
+    )
+}
+
+fn statistics_with_clause(sampling: StatisticsSampling, sample_percent: u32) -> String {
+    match sampling {
+        StatisticsSampling::Default => String::new(),
+        StatisticsSampling::Sample => {
+            format!(" WITH SAMPLE {} PERCENT", sample_percent.clamp(1, 100))
+        }
+        StatisticsSampling::Resample => " WITH RESAMPLE".to_string(),
+        StatisticsSampling::Fullscan => " WITH FULLSCAN".to_string(),
+    }
+}
+
+pub fn update_statistics_sql(
+    schema: &str,
+    table: &str,
+    stats: &str,
+    sampling: StatisticsSampling,
+    sample_percent: u32,
+) -> String {
+    format!(
+        "UPDATE STATISTICS [{}].[{}] [{}]{};",
+        bracket_escape(schema),
+        bracket_escape(table),
+        bracket_escape(stats),
+        statistics_with_clause(sampling, sample_percent),
+    )
+}
+
+/// Updates every statistic of the table.
+pub fn update_table_statistics_sql(
+    schema: &str,
+    table: &str,
+    sampling: StatisticsSampling,
+    sample_percent: u32,
+) -> String {
+    format!(
+        "UPDATE STATISTICS [{}].[{}]{};",
+        bracket_escape(schema),
+        bracket_escape(table),
+        statistics_with_clause(sampling, sample_percent),
     )
 }
 
+/// Heaps have no name in `sys.indexes`; they are reported under their type name.
+pub const HEAP_INDEX_NAME: &str = "HEAP";
+
 fn row_to_index_info(row: &Row, db_name: &str) -> Option<IndexInfo> {
     let schema_name: &str = row.get(0)?;
     let table_name: &str = row.get(1)?;
-    let index_name: &str = row.get(2)?;
+    let index_type = row.get::<i32, _>(11);
+    let is_heap = index_type == Some(0);
+    let index_name: &str = match row.get(2) {
+        Some(name) => name,
+        None if is_heap => HEAP_INDEX_NAME,
+        None => return None,
+    };
     let frag_percent = row
         .get::<f64, _>(3)
         .or_else(|| row.get::<f32, _>(3).map(f64::from))?;
     let page_count = row
         .get::<i64, _>(4)
         .or_else(|| row.get::<i32, _>(4).map(i64::from))?;
+    let page_density_percent = row.get::<f64, _>(5);
+    let record_count = row.get::<i64, _>(6);
+    let ghost_record_count = row.get::<i64, _>(7);
+    let partition_number = row.get::<i32, _>(8).unwrap_or(1);
+    let partition_count = row.get::<i32, _>(9).unwrap_or(1);
+    let forwarded_record_count = row.get::<i64, _>(10);
 
     Some(IndexInfo {
         database_name: db_name.to_string(),
@@ -84,36 +544,398 @@ fn row_to_index_info(row: &Row, db_name: &str) -> Option<IndexInfo> {
         index_name: index_name.to_string(),
         fragmentation_percent: frag_percent,
         page_count,
+        page_density_percent,
+        record_count,
+        ghost_record_count,
+        partition_number,
+        partition_count,
+        forwarded_record_count,
+        paused_rebuild_percent: None,
+        index_kind: match index_type {
+            Some(0) => IndexKind::Heap,
+            Some(3) => IndexKind::Xml,
+            Some(4) => IndexKind::Spatial,
+            _ => IndexKind::Rowstore,
+        },
+        has_legacy_lob: row.get::<bool, _>(12).unwrap_or(false),
+        has_max_lob: row.get::<bool, _>(13).unwrap_or(false),
+        columnstore: None,
+        excluded_by: None,
+    })
+}
+
+fn row_to_columnstore_info(row: &Row, db_name: &str) -> Option<IndexInfo> {
+    let schema_name: &str = row.get(0)?;
+    let table_name: &str = row.get(1)?;
+    let index_name: &str = row.get(2)?;
+    let deleted_percent = row.get::<f64, _>(3)?;
+    let page_count = row.get::<i64, _>(4)?;
+    let partition_number = row.get::<i32, _>(5).unwrap_or(1);
+    let partition_count = row.get::<i32, _>(6).unwrap_or(1);
+    let index_kind = match row.get::<i32, _>(7)? {
+        5 => IndexKind::ClusteredColumnstore,
+        _ => IndexKind::NonclusteredColumnstore,
+    };
+
+    Some(IndexInfo {
+        database_name: db_name.to_string(),
+        schema_name: schema_name.to_string(),
+        table_name: table_name.to_string(),
+        index_name: index_name.to_string(),
+        fragmentation_percent: deleted_percent,
+        page_count,
+        page_density_percent: None,
+        record_count: None,
+        ghost_record_count: None,
+        partition_number,
+        partition_count,
+        forwarded_record_count: None,
+        paused_rebuild_percent: None,
+        index_kind,
+        has_legacy_lob: false,
+        has_max_lob: false,
+        columnstore: Some(ColumnstoreStats {
+            total_rows: row.get::<i64, _>(8).unwrap_or(0),
+            deleted_rows: row.get::<i64, _>(9).unwrap_or(0),
+            open_row_groups: row.get::<i32, _>(10).unwrap_or(0),
+            closed_row_groups: row.get::<i32, _>(11).unwrap_or(0),
+            compressed_row_groups: row.get::<i32, _>(12).unwrap_or(0),
+        }),
+        excluded_by: None,
     })
 }
 
 pub async fn fetch_fragmented_indexes(
     client: &mut Client<Compat<TcpStream>>,
     db_name: &str,
-) -> Result<Vec<IndexInfo>, String> {
+    options: &MaintenanceOptions,
+) -> Result<Vec<IndexInfo>, SqlError> {
     let stream = client
-        .query(GET_FRAGMENTED_INDEXES, &[])
-        .await
-        .map_err(|e| e.to_string())?;
+        .query(
+            GET_FRAGMENTED_INDEXES,
+            &[
+                &options.scan_mode.as_sql(),
+                &options.min_page_count,
+                &options.max_page_count,
+                &options.include_heaps,
+            ],
+        )
+        .await?;
 
-    let rows = stream.into_results().await.map_err(|e| e.to_string())?;
+    let rows = stream.into_results().await?;
 
-    Ok(rows
+    let mut indexes: Vec<IndexInfo> = rows
         .into_iter()
         .flatten()
         .filter_map(|row| row_to_index_info(&row, db_name))
+        .collect();
+
+    indexes.extend(fetch_columnstore_indexes(client, db_name, options).await?);
+    indexes.sort_by(|a, b| b.fragmentation_percent.total_cmp(&a.fragmentation_percent));
+    apply_index_policy(&mut indexes, options);
+    Ok(indexes)
+}
+
+async fn fetch_columnstore_indexes(
+    client: &mut Client<Compat<TcpStream>>,
+    db_name: &str,
+    options: &MaintenanceOptions,
+) -> Result<Vec<IndexInfo>, SqlError> {
+    let supported = client
+        .query(HAS_COLUMNSTORE_ROW_GROUP_STATS, &[])
+        .await?
+        .into_row()
+        .await?
+        .and_then(|row| row.get::<i32, _>(0))
+        .unwrap_or(0);
+    if supported == 0 {
+        return Ok(Vec::new());
+    }
+
+    let stream = client
+        .query(
+            GET_COLUMNSTORE_INDEXES,
+            &[&options.min_page_count, &options.max_page_count],
+        )
+        .await?;
+
+    let rows = stream.into_results().await?;
+
+    Ok(rows
+        .into_iter()
+        .flatten()
+        .filter_map(|row| row_to_columnstore_info(&row, db_name))
         .collect())
 }
 
-pub async fn fetch_user_databases(
+/// Paused resumable rebuilds in the current database, as indexes to resume.
+pub async fn fetch_paused_resumable_operations(
+    client: &mut Client<Compat<TcpStream>>,
+    db_name: &str,
+) -> Result<Vec<IndexInfo>, SqlError> {
+    let stream = client.query(GET_PAUSED_RESUMABLE_OPERATIONS, &[]).await?;
+
+    let rows = stream.into_results().await?;
+
+    Ok(rows
+        .into_iter()
+        .flatten()
+        .filter_map(|row| {
+            let schema_name: &str = row.get(0)?;
+            let table_name: &str = row.get(1)?;
+            let index_name: &str = row.get(2)?;
+            Some(IndexInfo {
+                database_name: db_name.to_string(),
+                schema_name: schema_name.to_string(),
+                table_name: table_name.to_string(),
+                index_name: index_name.to_string(),
+                fragmentation_percent: 0.0,
+                page_count: row.get::<i64, _>(4).unwrap_or(0),
+                page_density_percent: None,
+                record_count: None,
+                ghost_record_count: None,
+                partition_number: 1,
+                partition_count: 1,
+                forwarded_record_count: None,
+                paused_rebuild_percent: Some(row.get::<f64, _>(3).unwrap_or(0.0)),
+                index_kind: IndexKind::Rowstore,
+                has_legacy_lob: false,
+                has_max_lob: false,
+                columnstore: None,
+                excluded_by: None,
+            })
+        })
+        .collect())
+}
+
+pub async fn fetch_statistics(
     client: &mut Client<Compat<TcpStream>>,
-) -> Result<Vec<String>, String> {
+    include_column_statistics: bool,
+) -> Result<Vec<StatisticsInfo>, SqlError> {
     let stream = client
-        .query(GET_USER_DATABASES, &[])
-        .await
-        .map_err(|e| e.to_string())?;
+        .query(GET_STATISTICS, &[&include_column_statistics])
+        .await?;
+
+    let rows = stream.into_results().await?;
+
+    Ok(rows
+        .into_iter()
+        .flatten()
+        .filter_map(|row| {
+            let schema_name: &str = row.get(0)?;
+            let table_name: &str = row.get(1)?;
+            let stats_name: &str = row.get(2)?;
+            Some(StatisticsInfo {
+                schema_name: schema_name.to_string(),
+                table_name: table_name.to_string(),
+                stats_name: stats_name.to_string(),
+                is_index_statistics: row.get::<bool, _>(3).unwrap_or(false),
+                rows: row.get::<i64, _>(4),
+                modification_counter: row.get::<i64, _>(5),
+                last_updated: row.get::<&str, _>(6).map(str::to_string),
+                days_since_update: row.get::<i32, _>(7),
+            })
+        })
+        .collect())
+}
+
+/// Reads per index in the current database, keyed by `schema.table.index`.
+pub async fn fetch_index_usage(
+    client: &mut Client<Compat<TcpStream>>,
+) -> Result<HashMap<String, i64>, SqlError> {
+    let stream = client.query(GET_INDEX_USAGE, &[]).await?;
+
+    let rows = stream.into_results().await?;
+
+    Ok(rows
+        .into_iter()
+        .flatten()
+        .filter_map(|row| {
+            let schema_name: &str = row.get(0)?;
+            let table_name: &str = row.get(1)?;
+            let index_name: &str = row.get(2).unwrap_or(HEAP_INDEX_NAME);
+            let reads = row.get::<i64, _>(3).unwrap_or(0);
+            Some((format!("{}.{}.{}", schema_name, table_name, index_name), reads))
+        })
+        .collect())
+}
+
+pub async fn fetch_log_space_usage(
+    client: &mut Client<Compat<TcpStream>>,
+) -> Result<LogSpaceUsage, SqlError> {
+    let row = client
+        .query(GET_LOG_SPACE_USAGE, &[])
+        .await?
+        .into_row()
+        .await?
+        .ok_or_else(|| SqlError::Other("Log space usage not available".to_string()))?;
+    Ok(LogSpaceUsage {
+        used_percent: row.get::<f64, _>(0).unwrap_or(0.0),
+        total_mb: row.get::<f64, _>(1).unwrap_or(0.0),
+        log_reuse_wait: row.get::<&str, _>(2).unwrap_or_default().to_string(),
+    })
+}
 
-    let rows = stream.into_results().await.map_err(|e| e.to_string())?;
+pub async fn fetch_server_info(
+    client: &mut Client<Compat<TcpStream>>,
+) -> Result<ServerInfo, SqlError> {
+    let row = client
+        .query(GET_SERVER_INFO, &[])
+        .await?
+        .into_row()
+        .await?
+        .ok_or_else(|| SqlError::Other("Server edition not available".to_string()))?;
+
+    let edition: &str = row.get(0).unwrap_or_default();
+    let engine_edition = row.get::<i32, _>(1).unwrap_or(0);
+    let product_version: &str = row.get(2).unwrap_or_default();
+    // ProductVersion looks like "15.0.2000.5"; the leading component is the major version.
+    let major_version = product_version
+        .split('.')
+        .next()
+        .and_then(|v| v.parse::<i32>().ok())
+        .unwrap_or(0);
+
+    Ok(ServerInfo { edition: edition.to_string(), engine_edition, major_version })
+}
+
+/// Returns `(auth_scheme, login_name)` for the current session.
+pub async fn fetch_session_auth(
+    client: &mut Client<Compat<TcpStream>>,
+) -> Result<(String, String), SqlError> {
+    let row = client
+        .query(GET_SESSION_AUTH, &[])
+        .await?
+        .into_row()
+        .await?
+        .ok_or_else(|| SqlError::Other("Session authentication info not available".to_string()))?;
+
+    let auth_scheme: &str = row.get(0).unwrap_or_default();
+    let login_name: &str = row.get(1).unwrap_or_default();
+    Ok((auth_scheme.to_string(), login_name.to_string()))
+}
+
+/// Login the server authenticated the current session as.
+pub async fn fetch_login_name(client: &mut Client<Compat<TcpStream>>) -> Result<String, SqlError> {
+    let row = client.query(GET_LOGIN_NAME, &[]).await?.into_row().await?;
+    Ok(row.and_then(|r| r.get::<&str, _>(0).map(str::to_string)).unwrap_or_default())
+}
+
+/// Availability Group databases by name, with the local replica's role and
+/// the group name. Empty when the server has no Availability Groups.
+pub async fn fetch_replica_roles(
+    client: &mut Client<Compat<TcpStream>>,
+) -> Result<HashMap<String, (ReplicaRole, String)>, SqlError> {
+    let stream = client.query(GET_REPLICA_ROLES, &[]).await?;
+
+    let rows = stream.into_results().await?;
+
+    Ok(rows
+        .into_iter()
+        .flatten()
+        .filter_map(|row| {
+            let name: &str = row.get(0)?;
+            let role = match row.get::<i32, _>(1)? {
+                1 => ReplicaRole::Primary,
+                2 => ReplicaRole::Secondary,
+                _ => ReplicaRole::Resolving,
+            };
+            let group: &str = row.get(2).unwrap_or_default();
+            Some((name.to_string(), (role, group.to_string())))
+        })
+        .collect())
+}
+
+pub async fn fetch_ag_queues(
+    client: &mut Client<Compat<TcpStream>>,
+) -> Result<Option<AgQueues>, SqlError> {
+    let row = client.query(GET_AG_QUEUES, &[]).await?.into_row().await?;
+    Ok(row.and_then(|row| {
+        Some(AgQueues {
+            log_send_queue_mb: row.get::<f64, _>(0)?,
+            redo_queue_mb: row.get::<f64, _>(1).unwrap_or(0.0),
+        })
+    }))
+}
+
+pub async fn fetch_session_id(client: &mut Client<Compat<TcpStream>>) -> Result<i32, SqlError> {
+    client
+        .query(GET_SESSION_ID, &[])
+        .await?
+        .into_row()
+        .await?
+        .and_then(|row| row.get::<i32, _>(0))
+        .ok_or_else(|| SqlError::Other("Session id not available".to_string()))
+}
+
+/// `None` when the session has no request running, or the side connection
+/// cannot see it (other sessions need VIEW SERVER STATE).
+pub async fn fetch_request_progress(
+    client: &mut Client<Compat<TcpStream>>,
+    session_id: i32,
+) -> Result<Option<IndexProgress>, SqlError> {
+    let row = client
+        .query(GET_REQUEST_PROGRESS, &[&session_id])
+        .await?
+        .into_row()
+        .await?;
+    Ok(row.map(|row| IndexProgress {
+        percent_complete: row.get::<f64, _>(0).unwrap_or(0.0),
+        estimated_remaining_secs: row.get::<f64, _>(1).unwrap_or(0.0),
+        wait_type: row.get::<&str, _>(2).map(str::to_string),
+        wait_secs: row.get::<f64, _>(3).unwrap_or(0.0),
+        cpu_time_ms: row.get::<i64, _>(4).unwrap_or(0),
+        logical_reads: row.get::<i64, _>(5).unwrap_or(0),
+        reads: row.get::<i64, _>(6).unwrap_or(0),
+        writes: row.get::<i64, _>(7).unwrap_or(0),
+        elapsed_secs: row.get::<f64, _>(8).unwrap_or(0.0),
+    }))
+}
+
+fn blocking_session(row: &Row) -> Option<BlockingSession> {
+    Some(BlockingSession {
+        session_id: row.get::<i32, _>(0)?,
+        wait_secs: row.get::<f64, _>(1).unwrap_or(0.0),
+        wait_type: row.get::<&str, _>(2).unwrap_or_default().to_string(),
+        login_name: row.get::<&str, _>(3).unwrap_or_default().to_string(),
+        host_name: row.get::<&str, _>(4).unwrap_or_default().to_string(),
+        program_name: row.get::<&str, _>(5).unwrap_or_default().to_string(),
+    })
+}
+
+/// Blocking around `session_id`, read from a side connection. Needs VIEW SERVER
+/// STATE to see other sessions.
+pub async fn fetch_blocking(
+    client: &mut Client<Compat<TcpStream>>,
+    session_id: i32,
+) -> Result<BlockingSnapshot, SqlError> {
+    let mut snapshot = BlockingSnapshot::default();
+    for (sql, slot) in [
+        (GET_BLOCKED_SESSION, &mut snapshot.blocking),
+        (GET_BLOCKING_SESSION, &mut snapshot.blocked_by),
+    ] {
+        let row = client.query(sql, &[&session_id]).await?.into_row().await?;
+        *slot = row.as_ref().and_then(blocking_session);
+    }
+    Ok(snapshot)
+}
+
+/// Online user databases that pass `filter`, with their Availability Group role.
+/// Roles need VIEW SERVER STATE; without it every database reports an unknown role.
+pub async fn fetch_user_databases(
+    client: &mut Client<Compat<TcpStream>>,
+    filter: &PatternFilter,
+) -> Result<Vec<UserDatabase>, SqlError> {
+    let stream = client.query(GET_USER_DATABASES, &[]).await?;
+
+    let rows = stream.into_results().await?;
+    let mut roles = match fetch_replica_roles(client).await {
+        Ok(roles) => Some(roles),
+        Err(e) => {
+            eprintln!("Failed to read Availability Group roles: {e}");
+            None
+        }
+    };
 
     Ok(rows
         .into_iter()
@@ -122,5 +944,188 @@ pub async fn fetch_user_databases(
             let name: Option<&str> = row.get(0);
             name.map(|s| s.to_string())
         })
+        .filter(|name| database_rejection(filter, name).is_none())
+        .map(|name| match roles.as_mut().map(|roles| roles.remove(&name)) {
+            Some(Some((replica_role, group))) => {
+                UserDatabase { name, replica_role, availability_group: Some(group) }
+            }
+            Some(None) => {
+                UserDatabase { name, replica_role: ReplicaRole::None, availability_group: None }
+            }
+            None => {
+                UserDatabase { name, replica_role: ReplicaRole::Unknown, availability_group: None }
+            }
+        })
         .collect())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn online() -> RebuildOptions {
+        RebuildOptions { online: true, ..Default::default() }
+    }
+
+    #[test]
+    fn rebuild_defaults_to_online_only() {
+        assert_eq!(
+            rebuild_index_sql("dbo", "Orders", "IX_Orders_Date", None, &online()),
+            "ALTER INDEX [IX_Orders_Date] ON [dbo].[Orders] REBUILD WITH (ONLINE = ON);"
+        );
+    }
+
+    #[test]
+    fn rebuild_with_all_options() {
+        let options = RebuildOptions {
+            online: false,
+            maxdop: 4,
+            sort_in_tempdb: true,
+            fill_factor: 90,
+            pad_index: true,
+            data_compression: DataCompression::Page,
+            ..Default::default()
+        };
+        assert_eq!(
+            rebuild_index_sql("dbo", "Orders", "PK_Orders", None, &options),
+            "ALTER INDEX [PK_Orders] ON [dbo].[Orders] REBUILD WITH (FILLFACTOR = 90, \
+             PAD_INDEX = ON, SORT_IN_TEMPDB = ON, ONLINE = OFF, MAXDOP = 4, DATA_COMPRESSION = PAGE);"
+        );
+    }
+
+    #[test]
+    fn rebuild_keeps_fill_factor_and_compression_by_default() {
+        let sql = rebuild_index_sql("dbo", "Orders", "PK_Orders", None, &online());
+        assert!(!sql.contains("FILLFACTOR"));
+        assert!(!sql.contains("DATA_COMPRESSION"));
+    }
+
+    #[test]
+    fn partition_rebuild_drops_fill_factor_and_pad_index() {
+        let options = RebuildOptions {
+            fill_factor: 80,
+            pad_index: true,
+            data_compression: DataCompression::Row,
+            ..online()
+        };
+        assert_eq!(
+            rebuild_index_sql("dbo", "Sales", "CIX_Sales", Some(3), &options),
+            "ALTER INDEX [CIX_Sales] ON [dbo].[Sales] REBUILD PARTITION = 3 \
+             WITH (ONLINE = ON, DATA_COMPRESSION = ROW);"
+        );
+    }
+
+    #[test]
+    fn resumable_rebuild_omits_sort_in_tempdb() {
+        let options = RebuildOptions {
+            resumable: true,
+            max_duration_minutes: 60,
+            sort_in_tempdb: true,
+            maxdop: 2,
+            ..online()
+        };
+        assert_eq!(
+            rebuild_index_sql("dbo", "Orders", "PK_Orders", None, &options),
+            "ALTER INDEX [PK_Orders] ON [dbo].[Orders] REBUILD WITH (ONLINE = ON, \
+             RESUMABLE = ON, MAX_DURATION = 60 MINUTES, MAXDOP = 2);"
+        );
+    }
+
+    #[test]
+    fn online_rebuild_waits_at_low_priority() {
+        let options = RebuildOptions {
+            low_priority: Some(LowPriorityWait {
+                max_duration_minutes: 5,
+                abort_after_wait: AbortAfterWait::SelfAbort,
+            }),
+            ..online()
+        };
+        assert_eq!(
+            rebuild_index_sql("dbo", "Orders", "PK_Orders", None, &options),
+            "ALTER INDEX [PK_Orders] ON [dbo].[Orders] REBUILD WITH (ONLINE = ON \
+             (WAIT_AT_LOW_PRIORITY (MAX_DURATION = 5 MINUTES, ABORT_AFTER_WAIT = SELF)));"
+        );
+    }
+
+    #[test]
+    fn offline_rebuild_ignores_low_priority() {
+        let options = RebuildOptions {
+            low_priority: Some(LowPriorityWait {
+                max_duration_minutes: 5,
+                abort_after_wait: AbortAfterWait::Blockers,
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            rebuild_index_sql("dbo", "Orders", "PK_Orders", None, &options),
+            "ALTER INDEX [PK_Orders] ON [dbo].[Orders] REBUILD WITH (ONLINE = OFF);"
+        );
+    }
+
+    #[test]
+    fn resume_with_max_duration_and_low_priority() {
+        let wait = LowPriorityWait { max_duration_minutes: 2, abort_after_wait: AbortAfterWait::None };
+        assert_eq!(
+            resume_index_sql("dbo", "Orders", "PK_Orders", 30, Some(&wait)),
+            "ALTER INDEX [PK_Orders] ON [dbo].[Orders] RESUME WITH (MAX_DURATION = 30 MINUTES, \
+             WAIT_AT_LOW_PRIORITY (MAX_DURATION = 2 MINUTES, ABORT_AFTER_WAIT = NONE));"
+        );
+    }
+
+    #[test]
+    fn heap_rebuild_uses_alter_table() {
+        let options = RebuildOptions {
+            data_compression: DataCompression::None,
+            ..online()
+        };
+        assert_eq!(
+            rebuild_heap_sql("stage", "Import", None, &options),
+            "ALTER TABLE [stage].[Import] REBUILD WITH (ONLINE = ON, DATA_COMPRESSION = NONE);"
+        );
+    }
+
+    #[test]
+    fn identifiers_are_bracket_escaped() {
+        assert_eq!(
+            reorganize_index_sql("my]schema", "t", "ix]1", None),
+            "ALTER INDEX [ix]]1] ON [my]]schema].[t] REORGANIZE;"
+        );
+    }
+
+    #[test]
+    fn session_settings() {
+        let options = MaintenanceOptions { lock_timeout_ms: 5000, ..Default::default() };
+        assert_eq!(
+            session_settings_sql(&options).as_deref(),
+            Some("SET LOCK_TIMEOUT 5000; SET DEADLOCK_PRIORITY LOW;")
+        );
+        let options = MaintenanceOptions { deadlock_priority_low: false, ..Default::default() };
+        assert_eq!(session_settings_sql(&options), None);
+    }
+
+    #[test]
+    fn statistics_sampling_clauses() {
+        let sql = |sampling, percent| update_statistics_sql("dbo", "t", "st", sampling, percent);
+        assert_eq!(sql(StatisticsSampling::Default, 0), "UPDATE STATISTICS [dbo].[t] [st];");
+        assert_eq!(
+            sql(StatisticsSampling::Sample, 25),
+            "UPDATE STATISTICS [dbo].[t] [st] WITH SAMPLE 25 PERCENT;"
+        );
+        assert_eq!(
+            sql(StatisticsSampling::Sample, 0),
+            "UPDATE STATISTICS [dbo].[t] [st] WITH SAMPLE 1 PERCENT;"
+        );
+        assert_eq!(
+            sql(StatisticsSampling::Resample, 0),
+            "UPDATE STATISTICS [dbo].[t] [st] WITH RESAMPLE;"
+        );
+        assert_eq!(
+            sql(StatisticsSampling::Fullscan, 0),
+            "UPDATE STATISTICS [dbo].[t] [st] WITH FULLSCAN;"
+        );
+        assert_eq!(
+            update_table_statistics_sql("dbo", "t]", StatisticsSampling::Fullscan, 0),
+            "UPDATE STATISTICS [dbo].[t]]] WITH FULLSCAN;"
+        );
+    }
+}
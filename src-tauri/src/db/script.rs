@@ -0,0 +1,306 @@
+use crate::db::queries::{bracket_escape, quote_escape, FREE_PROC_CACHE};
+use crate::models::types::{
+    DatabasePlan, IndexPlan, MaintenanceAction, MaintenanceOptions, MaintenancePlan, StatisticsPlan,
+    StatisticsSampling,
+};
+
+const SEPARATOR: &str =
+    "-- ===========================================================================";
+
+/// Renders a maintenance plan as a standalone T-SQL script that can be run
+/// with sqlcmd or SSMS. Statements are taken verbatim from the plan, so the
+/// script executes exactly what the app would have executed.
+pub fn build_plan_script(plan: &MaintenancePlan) -> String {
+    let mut lines: Vec<String> = vec![
+        "-- Indexxorcist maintenance script".to_string(),
+        format!("-- Server:     {}", plan.server),
+        format!("-- Generated:  {}", plan.generated_at),
+        format!(
+            "-- Thresholds: reorganize >= {}%, rebuild >= {}%, online rebuild = {}",
+            plan.options.reorganize_threshold,
+            plan.options.rebuild_threshold,
+            if plan.options.rebuild_online { "ON" } else { "OFF" },
+        ),
+        format!(
+            "-- Columnstore: reorganize >= {}% deleted rows, rebuild >= {}% deleted rows",
+            plan.options.columnstore_reorganize_threshold,
+            plan.options.columnstore_rebuild_threshold,
+        ),
+        statistics_header(&plan.options),
+        format!("-- Scan mode:  {}", plan.options.scan_mode.as_sql()),
+        String::new(),
+        "SET NOCOUNT ON;".to_string(),
+        "GO".to_string(),
+    ];
+
+    for db_plan in &plan.database_plans {
+        lines.push(String::new());
+        push_database_block(&mut lines, db_plan, plan.options.free_proc_cache);
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// File name for an exported script: the server and export time, with anything
+/// that is not safe in a file name replaced.
+pub fn script_file_name(server: &str, timestamp: &str) -> String {
+    let server: String = server
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '.' { c } else { '_' })
+        .collect();
+    format!("indexxorcist-{}-{}.sql", server.trim_matches('.'), timestamp)
+}
+
+fn statistics_header(options: &MaintenanceOptions) -> String {
+    if !options.update_statistics {
+        return "-- Statistics: FULLSCAN after index operations that do not refresh them".to_string();
+    }
+    let mut line = format!(
+        "-- Statistics: update when >= {}% of rows modified",
+        options.statistics_modification_threshold
+    );
+    if options.statistics_max_age_days > 0 {
+        line.push_str(&format!(" or older than {} days", options.statistics_max_age_days));
+    }
+    let sampling = match options.statistics_sampling {
+        StatisticsSampling::Default => "default".to_string(),
+        StatisticsSampling::Sample => format!("{}%", options.statistics_sample_percent),
+        StatisticsSampling::Resample => "RESAMPLE".to_string(),
+        StatisticsSampling::Fullscan => "FULLSCAN".to_string(),
+    };
+    line.push_str(&format!(", sampling {}", sampling));
+    line
+}
+
+fn push_database_block(lines: &mut Vec<String>, db_plan: &DatabasePlan, free_proc_cache: bool) {
+    let db = &db_plan.database_name;
+    lines.push(SEPARATOR.to_string());
+    lines.push(format!("-- Database: {}", db));
+    lines.push(SEPARATOR.to_string());
+
+    if let Some(err) = &db_plan.error {
+        lines.push(format!("-- Not analysed: {}", err.replace('\n', " ")));
+        return;
+    }
+
+    let (deferred, operations): (Vec<&IndexPlan>, Vec<&IndexPlan>) = db_plan
+        .indexes
+        .iter()
+        .filter(|i| i.action != MaintenanceAction::Skip)
+        .partition(|i| i.deferred);
+    for index in &deferred {
+        lines.push(format!(
+            "-- Not included: {}.{}.{} ({})",
+            index.schema_name,
+            index.table_name,
+            index.index_name,
+            index.reason.as_deref().unwrap_or("deferred")
+        ));
+    }
+    if operations.is_empty() && db_plan.statistics.is_empty() {
+        lines.push("-- No indexes above the reorganize threshold and no statistics due.".to_string());
+        return;
+    }
+
+    lines.push(format!("USE [{}];", bracket_escape(db)));
+    lines.push("GO".to_string());
+    lines.push(format!(
+        "PRINT N'{}: {} index operation(s), {} statistics update(s)';",
+        quote_escape(db),
+        operations.len(),
+        db_plan.statistics.len()
+    ));
+
+    let total = operations.len();
+    for (n, index) in operations.iter().enumerate() {
+        push_index_block(lines, index, n + 1, total);
+    }
+
+    // The app runs the statistics pass after all index operations of a database.
+    let total = db_plan.statistics.len();
+    for (n, stat) in db_plan.statistics.iter().enumerate() {
+        push_statistics_block(lines, stat, n + 1, total);
+    }
+
+    // Mirrors the app, which clears the plan cache once per database after index maintenance.
+    if free_proc_cache && !operations.is_empty() {
+        lines.push(format!("PRINT N'{}: clearing plan cache';", quote_escape(db)));
+        lines.push(FREE_PROC_CACHE.to_string());
+        lines.push("GO".to_string());
+    }
+}
+
+fn push_index_block(lines: &mut Vec<String>, index: &IndexPlan, current: usize, total: usize) {
+    let Some(sql) = &index.sql else { return };
+    let action = match index.action {
+        MaintenanceAction::Rebuild => "REBUILD",
+        MaintenanceAction::Reorganize => "REORGANIZE",
+        MaintenanceAction::RebuildHeap => "REBUILD HEAP",
+        MaintenanceAction::Skip => "SKIP",
+    };
+    let mut label = format!("{}.{}.{}", index.schema_name, index.table_name, index.index_name);
+    if let Some(n) = index.partition_number {
+        label.push_str(&format!(" partition {}", n));
+    }
+
+    lines.push(format!(
+        "PRINT N'[{}/{}] {} {} ({:.1}% fragmented, {} pages)';",
+        current,
+        total,
+        action,
+        quote_escape(&label),
+        index.fragmentation_percent,
+        index.page_count
+    ));
+    if let Some(reason) = &index.reason {
+        lines.push(format!("-- {}", reason));
+    }
+    lines.push("BEGIN TRY".to_string());
+    lines.push(format!("    {}", sql));
+    lines.push("    PRINT N'    done';".to_string());
+    if let Some(statistics_sql) = &index.statistics_sql {
+        lines.push(format!("    {}", statistics_sql));
+    }
+    lines.push("END TRY".to_string());
+    lines.push("BEGIN CATCH".to_string());
+    lines.push(
+        "    PRINT N'    FAILED (' + CAST(ERROR_NUMBER() AS nvarchar(10)) + N'): ' + ERROR_MESSAGE();"
+            .to_string(),
+    );
+    lines.push("END CATCH".to_string());
+    lines.push("GO".to_string());
+}
+
+fn push_statistics_block(lines: &mut Vec<String>, stat: &StatisticsPlan, current: usize, total: usize) {
+    let label = format!("{}.{}.{}", stat.schema_name, stat.table_name, stat.stats_name);
+    lines.push(format!(
+        "PRINT N'[{}/{}] UPDATE STATISTICS {} ({})';",
+        current,
+        total,
+        quote_escape(&label),
+        quote_escape(&stat.reason)
+    ));
+    lines.push("BEGIN TRY".to_string());
+    lines.push(format!("    {}", stat.sql));
+    lines.push("    PRINT N'    done';".to_string());
+    lines.push("END TRY".to_string());
+    lines.push("BEGIN CATCH".to_string());
+    lines.push(
+        "    PRINT N'    FAILED (' + CAST(ERROR_NUMBER() AS nvarchar(10)) + N'): ' + ERROR_MESSAGE();"
+            .to_string(),
+    );
+    lines.push("END CATCH".to_string());
+    lines.push("GO".to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::queries::{rebuild_index_sql, update_statistics_sql, RebuildOptions};
+
+    fn plan(database_plans: Vec<DatabasePlan>) -> MaintenancePlan {
+        MaintenancePlan {
+            profile_id: "p1".to_string(),
+            server: "sql01".to_string(),
+            generated_at: "2026-01-01T00:00:00Z".to_string(),
+            options: MaintenanceOptions { free_proc_cache: true, ..Default::default() },
+            database_plans,
+        }
+    }
+
+    /// Builds the fixture's SQL with the same builders a plan uses.
+    fn index(name: &str, action: MaintenanceAction) -> IndexPlan {
+        let online = RebuildOptions { online: true, ..Default::default() };
+        let sql = match action {
+            MaintenanceAction::Skip => None,
+            _ => Some(rebuild_index_sql("dbo", "Orders", name, None, &online)),
+        };
+        IndexPlan {
+            schema_name: "dbo".to_string(),
+            table_name: "Orders".to_string(),
+            index_name: name.to_string(),
+            partition_number: None,
+            fragmentation_percent: 42.0,
+            page_count: 1000,
+            page_density_percent: None,
+            action,
+            sql,
+            statistics_sql: None,
+            reason: None,
+            deferred: false,
+        }
+    }
+
+    fn statistic() -> StatisticsPlan {
+        StatisticsPlan {
+            schema_name: "dbo".to_string(),
+            table_name: "Orders".to_string(),
+            stats_name: "ST_Orders_Date".to_string(),
+            rows: Some(100),
+            modification_counter: Some(50),
+            last_updated: None,
+            sql: update_statistics_sql(
+                "dbo",
+                "Orders",
+                "ST_Orders_Date",
+                StatisticsSampling::Sample,
+                20,
+            ),
+            reason: "50% of rows modified".to_string(),
+        }
+    }
+
+    #[test]
+    fn database_block_batches_escapes_and_wraps() {
+        let rebuild = "ALTER INDEX [IX_O'Neil] ON [dbo].[Orders] REBUILD WITH (ONLINE = ON);";
+        let script = build_plan_script(&plan(vec![DatabasePlan {
+            database_name: "Bob's]Shop".to_string(),
+            indexes: vec![
+                index("IX_O'Neil", MaintenanceAction::Rebuild),
+                index("IX_Orders_Date", MaintenanceAction::Skip),
+            ],
+            statistics: vec![],
+            error: None,
+        }]));
+
+        assert!(script.contains("USE [Bob's]]Shop];\nGO\n"));
+        assert!(script.contains("PRINT N'Bob''s]Shop: 1 index operation(s), 0 statistics update(s)';"));
+        assert!(script.contains("PRINT N'[1/1] REBUILD dbo.Orders.IX_O''Neil (42.0% fragmented, 1000 pages)';"));
+        assert!(script.contains(&format!(
+            "BEGIN TRY\n    {}\n    PRINT N'    done';\nEND TRY\nBEGIN CATCH\n",
+            rebuild
+        )));
+        assert!(script.contains("END CATCH\nGO"));
+        assert!(!script.contains("IX_Orders_Date"));
+        assert!(script.contains("DBCC FREEPROCCACHE;\nGO"));
+    }
+
+    #[test]
+    fn plan_cache_is_only_cleared_after_index_operations() {
+        let mut deferred = index("IX_Orders_Total", MaintenanceAction::Rebuild);
+        deferred.deferred = true;
+        deferred.reason = Some("Deferred: the maintenance window has closed".to_string());
+        let script = build_plan_script(&plan(vec![DatabasePlan {
+            database_name: "Sales".to_string(),
+            indexes: vec![deferred],
+            statistics: vec![statistic()],
+            error: None,
+        }]));
+
+        assert!(script.contains(
+            "BEGIN TRY\n    UPDATE STATISTICS [dbo].[Orders] [ST_Orders_Date] WITH SAMPLE 20 PERCENT;\n"
+        ));
+        assert!(script.contains("-- Not included: dbo.Orders.IX_Orders_Total (Deferred: the maintenance window has closed)"));
+        assert!(!script.contains("ALTER INDEX [IX_Orders_Total]"));
+        assert!(!script.contains("FREEPROCCACHE"));
+    }
+
+    #[test]
+    fn script_file_name_is_sanitized() {
+        assert_eq!(
+            script_file_name("..\\sql01\\PROD/x:1", "20260101-010203"),
+            "indexxorcist-_sql01_PROD_x_1-20260101-010203.sql"
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use tokio::net::{lookup_host, UdpSocket};
+use tokio::time::{timeout, Duration};
+
+/// UDP port the SQL Server Browser service listens on.
+pub const SQL_BROWSER_PORT: u16 = 1434;
+
+/// How long to wait for the browser to answer before giving up.
+const SQL_BROWSER_TIMEOUT_MS: u64 = 3000;
+
+/// CLNT_UCAST_INST request type from the SQL Server Resolution Protocol (MS-SQLR).
+const CLNT_UCAST_INST: u8 = 0x04;
+/// SVR_RESP response type from MS-SQLR.
+const SVR_RESP: u8 = 0x05;
+
+/// Asks the SQL Server Browser on `host` for the TCP port of `instance`.
+pub async fn resolve_instance_port(host: &str, instance: &str) -> Result<u16, String> {
+    let addr = lookup_host((host, SQL_BROWSER_PORT))
+        .await
+        .map_err(|e| format!("Could not resolve host {}: {}", host, e))?
+        .next()
+        .ok_or_else(|| format!("Could not resolve host {}", host))?;
+
+    let local_bind = if addr.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" };
+    let socket = UdpSocket::bind(local_bind)
+        .await
+        .map_err(|e| format!("Failed to open UDP socket for SQL Browser: {}", e))?;
+
+    let mut request = Vec::with_capacity(instance.len() + 2);
+    request.push(CLNT_UCAST_INST);
+    request.extend_from_slice(instance.as_bytes());
+    request.push(0);
+    socket
+        .send_to(&request, addr)
+        .await
+        .map_err(|e| format!("Failed to query SQL Browser on {}:{}: {}", host, SQL_BROWSER_PORT, e))?;
+
+    let mut buf = vec![0u8; 4096];
+    let len = timeout(Duration::from_millis(SQL_BROWSER_TIMEOUT_MS), socket.recv(&mut buf))
+        .await
+        .map_err(|_| {
+            format!(
+                "SQL Server Browser on {}:{} did not answer within {}ms while resolving instance '{}'. \
+                 Check that the SQL Browser service is running and UDP {} is reachable, or clear the instance name and connect by port.",
+                host, SQL_BROWSER_PORT, SQL_BROWSER_TIMEOUT_MS, instance, SQL_BROWSER_PORT
+            )
+        })?
+        .map_err(|e| format!("SQL Browser on {}:{} failed: {}", host, SQL_BROWSER_PORT, e))?;
+
+    parse_browser_response(&buf[..len], instance)
+}
+
+/// Extracts the TCP port from an SVR_RESP message. The payload is one record
+/// per instance, each a `;`-separated key/value list ending in `;;`, such as
+/// `ServerName;HOST;InstanceName;SQL01;IsClustered;No;Version;15.0.2000.5;tcp;49712;;`.
+fn parse_browser_response(msg: &[u8], instance: &str) -> Result<u16, String> {
+    let invalid = || format!("SQL Browser returned an invalid response for instance '{}'", instance);
+    if msg.len() < 3 || msg[0] != SVR_RESP {
+        return Err(invalid());
+    }
+    // Bytes 1-2 are the little-endian payload size; anything shorter was cut off.
+    let size = u16::from_le_bytes([msg[1], msg[2]]) as usize;
+    let payload = msg.get(3..3 + size).ok_or_else(invalid)?;
+    let payload = String::from_utf8_lossy(payload);
+
+    let record = payload
+        .split(";;")
+        .map(|record| {
+            let fields: Vec<&str> = record.split(';').collect();
+            fields
+                .chunks(2)
+                .filter(|kv| kv.len() == 2)
+                .map(|kv| (kv[0], kv[1]))
+                .collect::<Vec<_>>()
+        })
+        .find(|record| {
+            record.iter().any(|(k, v)| {
+                k.eq_ignore_ascii_case("InstanceName") && v.eq_ignore_ascii_case(instance)
+            })
+        });
+    let port = record.and_then(|record| {
+        record.into_iter().find(|(k, _)| k.eq_ignore_ascii_case("tcp")).map(|(_, v)| v)
+    });
+
+    match port {
+        Some(p) => p.parse::<u16>().map_err(|_| {
+            format!("SQL Browser returned an invalid TCP port '{}' for instance '{}'", p, instance)
+        }),
+        None => Err(format!(
+            "Instance '{}' does not have TCP/IP enabled or was not found by SQL Browser",
+            instance
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn svr_resp(payload: &str) -> Vec<u8> {
+        let mut msg = vec![SVR_RESP];
+        msg.extend_from_slice(&(payload.len() as u16).to_le_bytes());
+        msg.extend_from_slice(payload.as_bytes());
+        msg
+    }
+
+    #[test]
+    fn finds_the_requested_instance_port() {
+        let msg = svr_resp(
+            "ServerName;DB01;InstanceName;MSSQLSERVER;IsClustered;No;Version;16.0.1000.6;tcp;1433;;\
+             ServerName;DB01;InstanceName;SQL01;IsClustered;No;Version;15.0.2000.5;tcp;49712;np;\\\\DB01\\pipe\\MSSQL$SQL01\\sql\\query;;",
+        );
+        assert_eq!(parse_browser_response(&msg, "sql01"), Ok(49712));
+        assert_eq!(parse_browser_response(&msg, "MSSQLSERVER"), Ok(1433));
+        assert!(parse_browser_response(&msg, "SQL02").unwrap_err().contains("was not found"));
+    }
+
+    #[test]
+    fn instance_without_tcp_is_reported() {
+        let msg = svr_resp(
+            "ServerName;DB01;InstanceName;SQL01;IsClustered;No;Version;15.0.2000.5;np;\\\\DB01\\pipe\\sql\\query;;",
+        );
+        assert!(parse_browser_response(&msg, "SQL01").unwrap_err().contains("TCP/IP enabled"));
+    }
+
+    #[test]
+    fn truncated_or_garbage_input_is_rejected() {
+        let full = svr_resp("ServerName;DB01;InstanceName;SQL01;tcp;49712;;");
+        let invalid = Err("SQL Browser returned an invalid response for instance 'SQL01'".to_string());
+        assert_eq!(parse_browser_response(&full[..full.len() - 4], "SQL01"), invalid);
+        assert_eq!(parse_browser_response(&[SVR_RESP, 0x10], "SQL01"), invalid);
+        assert_eq!(parse_browser_response(b"HTTP/1.1 400", "SQL01"), invalid);
+        assert!(parse_browser_response(&svr_resp("InstanceName;SQL01;tcp;port;;"), "SQL01")
+            .unwrap_err()
+            .contains("invalid TCP port 'port'"));
+        assert!(parse_browser_response(&svr_resp("\u{fffd};;;"), "SQL01").is_err());
+    }
+}
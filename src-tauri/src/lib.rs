@@ -37,6 +37,8 @@ pub fn run() {
             commands::database::test_connection,
             commands::database::get_databases,
             commands::maintenance::run_maintenance,
+            commands::maintenance::plan_maintenance,
+            commands::maintenance::export_maintenance_script,
             commands::maintenance::pause_maintenance,
             commands::maintenance::resume_maintenance,
             commands::maintenance::skip_database,
@@ -9,6 +9,12 @@ use specta::Type;
 #[serde(rename_all = "camelCase")]
 pub enum AuthType {
     SqlServer,
+    /// NTLM with explicit domain credentials (`DOMAIN\user` + password).
+    WindowsCredentials,
+    /// Entra ID service principal: `username` is the client ID, `password` the client secret.
+    AzureAdServicePrincipal,
+    /// Entra ID access token supplied by the user and stored as the profile's password.
+    AzureAdToken,
 }
 
 impl Default for AuthType {
@@ -27,12 +33,22 @@ pub struct ServerProfile {
     pub name: String,
     pub server: String,
     pub port: u16,
+    /// Named instance; when set, the port is resolved through the SQL Browser.
+    /// A `HOST\INSTANCE` value in `server` is accepted as well.
+    #[serde(default)]
+    pub instance_name: String,
     #[serde(default)]
     pub auth_type: AuthType,
     pub username: String,
     pub password: String,
     pub encrypt: bool,
     pub trust_server_certificate: bool,
+    /// Entra ID tenant (directory) ID — service principal auth only.
+    #[serde(default)]
+    pub tenant_id: String,
+    /// Overrides the Entra ID authority host (sovereign clouds, local mock endpoints).
+    #[serde(default)]
+    pub authority_host: Option<String>,
 }
 
 impl Default for ServerProfile {
@@ -42,11 +58,14 @@ impl Default for ServerProfile {
             name: String::new(),
             server: String::new(),
             port: 1433,
+            instance_name: String::new(),
             auth_type: AuthType::SqlServer,
             username: String::new(),
             password: String::new(),
             encrypt: true,
             trust_server_certificate: true,
+            tenant_id: String::new(),
+            authority_host: None,
         }
     }
 }
@@ -62,10 +81,16 @@ pub struct ServerProfileOnDisk {
     pub server: String,
     pub port: u16,
     #[serde(default)]
+    pub instance_name: String,
+    #[serde(default)]
     pub auth_type: AuthType,
     pub username: String,
     pub encrypt: bool,
     pub trust_server_certificate: bool,
+    #[serde(default)]
+    pub tenant_id: String,
+    #[serde(default)]
+    pub authority_host: Option<String>,
 }
 
 impl From<ServerProfile> for ServerProfileOnDisk {
@@ -75,10 +100,13 @@ impl From<ServerProfile> for ServerProfileOnDisk {
             name: p.name,
             server: p.server,
             port: p.port,
+            instance_name: p.instance_name,
             auth_type: p.auth_type,
             username: p.username,
             encrypt: p.encrypt,
             trust_server_certificate: p.trust_server_certificate,
+            tenant_id: p.tenant_id,
+            authority_host: p.authority_host,
         }
     }
 }
@@ -90,27 +118,310 @@ impl ServerProfileOnDisk {
             name: self.name,
             server: self.server,
             port: self.port,
+            instance_name: self.instance_name,
             auth_type: self.auth_type,
             username: self.username,
             password,
             encrypt: self.encrypt,
             trust_server_certificate: self.trust_server_certificate,
+            tenant_id: self.tenant_id,
+            authority_host: self.authority_host,
         }
     }
 }
 
+// ---------------------------------------------------------------------------
+// Connection test result
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Serialize, Deserialize, Clone, Type)]
+pub struct ConnectionTestResult {
+    /// Authentication method configured on the profile.
+    pub auth_type: AuthType,
+    /// `auth_scheme` reported by the server for this session (SQL, NTLM, KERBEROS);
+    /// `None` when the login may not read `sys.dm_exec_connections`.
+    pub auth_scheme: Option<String>,
+    /// Login the server authenticated the session as.
+    pub login_name: String,
+}
+
+// ---------------------------------------------------------------------------
+// Server info
+// ---------------------------------------------------------------------------
+
+/// Edition and engine version, detected once per run.
+#[derive(Debug, Serialize, Deserialize, Clone, Type)]
+pub struct ServerInfo {
+    /// `SERVERPROPERTY('Edition')`, e.g. "Standard Edition (64-bit)".
+    pub edition: String,
+    /// `SERVERPROPERTY('EngineEdition')`: 2 = Standard, 3 = Enterprise/Developer,
+    /// 4 = Express, 5 = Azure SQL Database, 8 = Azure SQL Managed Instance.
+    pub engine_edition: i32,
+    /// Major engine version: 11 = 2012, 12 = 2014, 13 = 2016, 14 = 2017, 15 = 2019, 16 = 2022.
+    pub major_version: i32,
+}
+
+impl ServerInfo {
+    /// Online index operations are an Enterprise feature; Azure SQL has them too.
+    pub fn supports_online_rebuild(&self) -> bool {
+        matches!(self.engine_edition, 3 | 5 | 8)
+    }
+
+    /// Whether the engine has the features of SQL Server `major`. Azure SQL always
+    /// runs the latest engine but reports a fixed 12.x product version.
+    pub fn at_least(&self, major: i32) -> bool {
+        matches!(self.engine_edition, 5 | 8) || self.major_version >= major
+    }
+}
+
+// ---------------------------------------------------------------------------
+// User databases
+// ---------------------------------------------------------------------------
+
+/// Role of the connected replica for a database in an Availability Group.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum ReplicaRole {
+    /// Not in an Availability Group.
+    #[default]
+    None,
+    Primary,
+    /// Read-only here; the run skips it.
+    Secondary,
+    /// Failing over or disconnected; the run skips it.
+    Resolving,
+    /// The roles could not be read (they need VIEW SERVER STATE); the run
+    /// treats the database as outside a group.
+    Unknown,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Type)]
+pub struct UserDatabase {
+    pub name: String,
+    pub replica_role: ReplicaRole,
+    pub availability_group: Option<String>,
+}
+
+/// Largest queues of the secondary replicas of a database, from the primary's
+/// `sys.dm_hadr_database_replica_states`.
+#[derive(Debug, Serialize, Deserialize, Clone, Type)]
+pub struct AgQueues {
+    pub log_send_queue_mb: f64,
+    pub redo_queue_mb: f64,
+}
+
 // ---------------------------------------------------------------------------
 // Maintenance options
 // ---------------------------------------------------------------------------
 
+/// `mode` argument of `sys.dm_db_index_physical_stats`. LIMITED only reads
+/// parent-level pages; SAMPLED and DETAILED also report page density and
+/// record counts at the cost of reading (some or all) leaf pages.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default, Type)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ScanMode {
+    #[default]
+    Limited,
+    Sampled,
+    Detailed,
+}
+
+impl ScanMode {
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            ScanMode::Limited => "LIMITED",
+            ScanMode::Sampled => "SAMPLED",
+            ScanMode::Detailed => "DETAILED",
+        }
+    }
+}
+
+/// What to do when `rebuild_online` is set but an index cannot be rebuilt online.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum OnlineFallback {
+    /// Rebuild with `ONLINE = OFF` instead.
+    #[default]
+    Offline,
+    /// Leave the index alone rather than take it offline.
+    Skip,
+}
+
+/// `DATA_COMPRESSION` for rowstore rebuilds. `Keep` omits the option so each
+/// index keeps its current compression.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default, Type)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DataCompression {
+    #[default]
+    Keep,
+    None,
+    Row,
+    Page,
+}
+
+impl DataCompression {
+    pub fn as_sql(&self) -> Option<&'static str> {
+        match self {
+            DataCompression::Keep => None,
+            DataCompression::None => Some("NONE"),
+            DataCompression::Row => Some("ROW"),
+            DataCompression::Page => Some("PAGE"),
+        }
+    }
+}
+
+/// `ABORT_AFTER_WAIT` of `WAIT_AT_LOW_PRIORITY`: what happens when the
+/// low-priority wait for the final schema-modification lock runs out.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default, Type)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AbortAfterWait {
+    /// Keep waiting with normal priority.
+    None,
+    /// Abort the rebuild itself.
+    #[default]
+    #[serde(rename = "SELF")]
+    SelfAbort,
+    /// Kill the sessions blocking the rebuild.
+    Blockers,
+}
+
+impl AbortAfterWait {
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            AbortAfterWait::None => "NONE",
+            AbortAfterWait::SelfAbort => "SELF",
+            AbortAfterWait::Blockers => "BLOCKERS",
+        }
+    }
+}
+
+/// How `UPDATE STATISTICS` samples the table.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default, Type)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum StatisticsSampling {
+    /// Let SQL Server pick the sample size.
+    #[default]
+    Default,
+    /// `SAMPLE n PERCENT`, with n from `statistics_sample_percent`.
+    Sample,
+    /// Reuse the sample rate of each statistic's last update.
+    Resample,
+    Fullscan,
+}
+
+/// Order in which a database's indexes are processed. Paused resumable rebuilds
+/// always come first.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum IndexPriority {
+    /// Most fragmented first.
+    #[default]
+    Fragmentation,
+    /// Highest fragmentation × page count first.
+    LargestBenefit,
+    /// Most read (seeks, scans and lookups since the last restart) first.
+    MostUsed,
+    /// Fewest pages first.
+    SmallestFirst,
+    /// Never maintained by this profile first, then longest since last maintained.
+    LeastRecentlyMaintained,
+}
+
+/// What the log usage guard does when a database's transaction log is too full.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum LogUsageAction {
+    /// Poll until usage drops, e.g. after a log backup, then continue.
+    #[default]
+    Wait,
+    /// Pause the whole run until it is resumed by hand.
+    Pause,
+}
+
+/// `LIKE`-style name patterns (`%` and `_`, case-insensitive) selecting the
+/// objects at one level. An object is kept when it matches no exclude pattern
+/// and, if any include patterns are set, at least one of them.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Type)]
+pub struct PatternFilter {
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+/// Option values replacing the run-wide ones for the databases, or tables,
+/// matching its patterns. `None` keeps the run-wide value.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Type)]
+pub struct OptionOverride {
+    /// `LIKE`-style database name pattern.
+    pub database: String,
+    /// `LIKE`-style table pattern, matched like [`MaintenanceOptions::table_filter`];
+    /// empty applies the override to the whole database.
+    #[serde(default)]
+    pub table: String,
+    #[serde(default)]
+    pub rebuild_threshold: Option<f64>,
+    #[serde(default)]
+    pub reorganize_threshold: Option<f64>,
+    #[serde(default)]
+    pub rebuild_online: Option<bool>,
+    #[serde(default)]
+    pub reorganize_only: Option<bool>,
+    #[serde(default)]
+    pub maxdop: Option<u32>,
+    #[serde(default)]
+    pub fill_factor: Option<u32>,
+    #[serde(default)]
+    pub data_compression: Option<DataCompression>,
+}
+
+impl OptionOverride {
+    pub fn apply_to(&self, options: &mut MaintenanceOptions) {
+        if let Some(v) = self.rebuild_threshold {
+            options.rebuild_threshold = v;
+        }
+        if let Some(v) = self.reorganize_threshold {
+            options.reorganize_threshold = v;
+        }
+        if let Some(v) = self.rebuild_online {
+            options.rebuild_online = v;
+        }
+        if let Some(v) = self.reorganize_only {
+            options.reorganize_only = v;
+        }
+        if let Some(v) = self.maxdop {
+            options.maxdop = v;
+        }
+        if let Some(v) = self.fill_factor {
+            options.fill_factor = v;
+        }
+        if let Some(v) = self.data_compression {
+            options.data_compression = v;
+        }
+    }
+}
+
 fn default_true() -> bool { true }
 fn default_rebuild_threshold() -> f64 { 30.0 }
 fn default_reorganize_threshold() -> f64 { 10.0 }
 fn default_retry_max_attempts() -> u32 { 3 }
 fn default_retry_base_delay_ms() -> u64 { 1000 }
 fn default_retry_max_delay_ms() -> u64 { 30000 }
+/// Deadlock victim, lock timeout, and the Azure SQL throttling, failover and
+/// reconfiguration errors.
+fn default_retryable_error_numbers() -> Vec<u32> {
+    vec![1205, 1222, 4221, 10928, 10929, 40197, 40501, 40613, 49918, 49919, 49920]
+}
 fn default_connection_timeout_ms() -> u64 { 30000 }
+fn default_application_name() -> String { "Indexxorcist".to_string() }
 fn default_max_parallel_databases() -> u32 { 4 }
+fn default_min_page_count() -> i64 { 100 }
+fn default_columnstore_rebuild_threshold() -> f64 { 50.0 }
+fn default_columnstore_reorganize_threshold() -> f64 { 20.0 }
+fn default_heap_forwarded_record_threshold() -> f64 { 10.0 }
+fn default_low_priority_max_duration_minutes() -> u32 { 5 }
+fn default_statistics_modification_threshold() -> f64 { 10.0 }
+fn default_statistics_sample_percent() -> u32 { 25 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Type)]
 pub struct MaintenanceOptions {
@@ -128,14 +439,167 @@ pub struct MaintenanceOptions {
     pub retry_base_delay_ms: u64,
     #[serde(default = "default_retry_max_delay_ms")]
     pub retry_max_delay_ms: u64,
+    /// SQL Server error numbers retried with backoff.
+    #[serde(default = "default_retryable_error_numbers")]
+    pub retryable_error_numbers: Vec<u32>,
+    /// Retry requests that hit `request_timeout_ms`, on a new connection once
+    /// the timed-out request was cancelled.
+    #[serde(default = "default_true")]
+    pub retry_timeouts: bool,
+    /// Retry requests whose connection failed underneath them, on a new connection.
+    #[serde(default = "default_true")]
+    pub retry_connection_errors: bool,
     #[serde(default = "default_connection_timeout_ms")]
     pub connection_timeout_ms: u64,
     #[serde(default)]
     pub request_timeout_ms: u64,
+    /// `SET LOCK_TIMEOUT` on maintenance connections (0 = wait indefinitely).
+    #[serde(default)]
+    pub lock_timeout_ms: u32,
+    /// `SET DEADLOCK_PRIORITY LOW` on maintenance connections, so a deadlock
+    /// always picks maintenance as the victim.
+    #[serde(default = "default_true")]
+    pub deadlock_priority_low: bool,
+    /// Application name maintenance connections log in with, as monitoring sees
+    /// it in `sys.dm_exec_sessions.program_name`.
+    #[serde(default = "default_application_name")]
+    pub application_name: String,
     #[serde(default)]
     pub parallel_databases: bool,
     #[serde(default = "default_max_parallel_databases")]
     pub max_parallel_databases: u32,
+    #[serde(default)]
+    pub scan_mode: ScanMode,
+    /// Rebuild when leaf page density falls below this percent (0 = disabled).
+    /// Only effective with SAMPLED or DETAILED scans, where density is reported.
+    #[serde(default)]
+    pub page_density_threshold: f64,
+    /// Indexes with fewer pages are not worth the maintenance overhead.
+    #[serde(default = "default_min_page_count")]
+    pub min_page_count: i64,
+    /// Indexes with more pages are left for a separate window (0 = no limit).
+    #[serde(default)]
+    pub max_page_count: i64,
+    /// Rebuild a columnstore index when this percent of its rows are deleted.
+    #[serde(default = "default_columnstore_rebuild_threshold")]
+    pub columnstore_rebuild_threshold: f64,
+    /// Reorganize a columnstore index when this percent of its rows are deleted.
+    #[serde(default = "default_columnstore_reorganize_threshold")]
+    pub columnstore_reorganize_threshold: f64,
+    /// Reorganize a columnstore index once it has this many OPEN or CLOSED
+    /// delta rowgroups waiting to be compressed (0 = disabled).
+    #[serde(default)]
+    pub columnstore_delta_rowgroup_threshold: i32,
+    /// Also analyse heaps (tables without a clustered index).
+    #[serde(default)]
+    pub include_heaps: bool,
+    /// Rebuild a heap when forwarded records reach this percent of its records
+    /// (0 = disabled). Forwarded records are only counted by SAMPLED/DETAILED scans.
+    #[serde(default = "default_heap_forwarded_record_threshold")]
+    pub heap_forwarded_record_threshold: f64,
+    /// Rebuild a heap when its extent fragmentation reaches this percent (0 = disabled).
+    #[serde(default)]
+    pub heap_fragmentation_threshold: f64,
+    #[serde(default)]
+    pub online_fallback: OnlineFallback,
+    /// Run online rebuilds with `RESUMABLE = ON` (SQL Server 2017+) so pausing or
+    /// stopping a run pauses the rebuild server-side instead of losing its work.
+    #[serde(default)]
+    pub resumable_rebuild: bool,
+    /// `MAX_DURATION` for resumable rebuilds, after which SQL Server pauses them (0 = no limit).
+    #[serde(default)]
+    pub resumable_max_duration_minutes: u32,
+    /// `MAXDOP` for rebuilds (0 = server default).
+    #[serde(default)]
+    pub maxdop: u32,
+    /// Sort in tempdb instead of the index's filegroup. Ignored by resumable rebuilds.
+    #[serde(default)]
+    pub sort_in_tempdb: bool,
+    /// `FILLFACTOR` for rebuilds (0 = keep each index's own fill factor).
+    #[serde(default)]
+    pub fill_factor: u32,
+    /// Apply the fill factor to intermediate index pages as well.
+    #[serde(default)]
+    pub pad_index: bool,
+    #[serde(default)]
+    pub data_compression: DataCompression,
+    /// Online rebuilds wait for their schema-modification lock with
+    /// `WAIT_AT_LOW_PRIORITY` instead of queueing ahead of other sessions.
+    #[serde(default)]
+    pub low_priority_wait: bool,
+    #[serde(default = "default_low_priority_max_duration_minutes")]
+    pub low_priority_max_duration_minutes: u32,
+    #[serde(default)]
+    pub abort_after_wait: AbortAfterWait,
+    /// Run the statistics pass after index maintenance. Off by default: it reads
+    /// every due statistic, on top of what the index pass already refreshed.
+    #[serde(default)]
+    pub update_statistics: bool,
+    /// Update a statistic once this percent of its rows were modified since
+    /// its last update (0 = disabled).
+    #[serde(default = "default_statistics_modification_threshold")]
+    pub statistics_modification_threshold: f64,
+    /// Update a modified statistic last updated at least this many days ago (0 = disabled).
+    #[serde(default)]
+    pub statistics_max_age_days: u32,
+    #[serde(default)]
+    pub statistics_sampling: StatisticsSampling,
+    /// Sample size for [`StatisticsSampling::Sample`].
+    #[serde(default = "default_statistics_sample_percent")]
+    pub statistics_sample_percent: u32,
+    /// Also maintain auto-created and user-created column statistics, not only
+    /// the statistics of indexes.
+    #[serde(default)]
+    pub include_column_statistics: bool,
+    /// Local time (`HH:MM`) at which the maintenance window closes; empty for no
+    /// end time. A time already past when the run starts means the next day.
+    #[serde(default)]
+    pub window_end_time: String,
+    /// Maximum run duration in minutes (0 = no limit). With an end time as
+    /// well, whichever comes first closes the window.
+    #[serde(default)]
+    pub max_run_minutes: u32,
+    #[serde(default)]
+    pub index_priority: IndexPriority,
+    #[serde(default)]
+    pub database_filter: PatternFilter,
+    #[serde(default)]
+    pub schema_filter: PatternFilter,
+    /// Patterns containing a `.` match `schema.table`, others the table name.
+    #[serde(default)]
+    pub table_filter: PatternFilter,
+    #[serde(default)]
+    pub index_filter: PatternFilter,
+    /// Reorganize indexes past the rebuild threshold instead of rebuilding them;
+    /// heaps, which cannot be reorganized, are skipped.
+    #[serde(default)]
+    pub reorganize_only: bool,
+    /// Applied in order, database-level overrides before table-level ones, so
+    /// later entries win.
+    #[serde(default)]
+    pub overrides: Vec<OptionOverride>,
+    /// Hold off index operations while the database's transaction log is at
+    /// least this percent full (0 = disabled).
+    #[serde(default)]
+    pub log_usage_max_percent: f64,
+    #[serde(default)]
+    pub log_usage_action: LogUsageAction,
+    /// Hold index operations while a secondary replica's log send queue is at
+    /// least this many MB (0 = disabled).
+    #[serde(default)]
+    pub ag_max_log_send_queue_mb: u32,
+    /// Hold index operations while a secondary replica's redo queue is at least
+    /// this many MB (0 = disabled).
+    #[serde(default)]
+    pub ag_max_redo_queue_mb: u32,
+    /// Cancel an index operation once another session has waited on it for this
+    /// many seconds (0 = disabled).
+    #[serde(default)]
+    pub max_blocking_secs: u32,
+    /// Cancel an index operation once it has itself been blocked for this many
+    /// seconds (0 = disabled).
+    #[serde(default)]
+    pub max_blocked_secs: u32,
 }
 
 impl Default for MaintenanceOptions {
@@ -148,10 +612,58 @@ impl Default for MaintenanceOptions {
             retry_max_attempts: 3,
             retry_base_delay_ms: 1000,
             retry_max_delay_ms: 30000,
+            retryable_error_numbers: default_retryable_error_numbers(),
+            retry_timeouts: true,
+            retry_connection_errors: true,
             connection_timeout_ms: 30000,
             request_timeout_ms: 0,
+            lock_timeout_ms: 0,
+            deadlock_priority_low: true,
+            application_name: default_application_name(),
             parallel_databases: false,
             max_parallel_databases: 4,
+            scan_mode: ScanMode::Limited,
+            page_density_threshold: 0.0,
+            min_page_count: 100,
+            max_page_count: 0,
+            columnstore_rebuild_threshold: 50.0,
+            columnstore_reorganize_threshold: 20.0,
+            columnstore_delta_rowgroup_threshold: 0,
+            include_heaps: false,
+            heap_forwarded_record_threshold: 10.0,
+            heap_fragmentation_threshold: 0.0,
+            online_fallback: OnlineFallback::Offline,
+            resumable_rebuild: false,
+            resumable_max_duration_minutes: 0,
+            maxdop: 0,
+            sort_in_tempdb: false,
+            fill_factor: 0,
+            pad_index: false,
+            data_compression: DataCompression::Keep,
+            low_priority_wait: false,
+            low_priority_max_duration_minutes: 5,
+            abort_after_wait: AbortAfterWait::SelfAbort,
+            update_statistics: false,
+            statistics_modification_threshold: 10.0,
+            statistics_max_age_days: 0,
+            statistics_sampling: StatisticsSampling::Default,
+            statistics_sample_percent: 25,
+            include_column_statistics: false,
+            window_end_time: String::new(),
+            max_run_minutes: 0,
+            index_priority: IndexPriority::Fragmentation,
+            database_filter: PatternFilter::default(),
+            schema_filter: PatternFilter::default(),
+            table_filter: PatternFilter::default(),
+            index_filter: PatternFilter::default(),
+            reorganize_only: false,
+            overrides: Vec::new(),
+            log_usage_max_percent: 0.0,
+            log_usage_action: LogUsageAction::Wait,
+            ag_max_log_send_queue_mb: 0,
+            ag_max_redo_queue_mb: 0,
+            max_blocking_secs: 0,
+            max_blocked_secs: 0,
         }
     }
 }
@@ -160,6 +672,37 @@ impl Default for MaintenanceOptions {
 // Index types
 // ---------------------------------------------------------------------------
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum IndexKind {
+    #[default]
+    Rowstore,
+    Heap,
+    Xml,
+    Spatial,
+    ClusteredColumnstore,
+    NonclusteredColumnstore,
+}
+
+impl IndexKind {
+    pub fn is_columnstore(&self) -> bool {
+        matches!(self, IndexKind::ClusteredColumnstore | IndexKind::NonclusteredColumnstore)
+    }
+}
+
+/// Rowgroup health of a columnstore index (or partition), aggregated from
+/// `sys.dm_db_column_store_row_group_physical_stats`.
+#[derive(Debug, Serialize, Deserialize, Clone, Type)]
+pub struct ColumnstoreStats {
+    pub total_rows: i64,
+    pub deleted_rows: i64,
+    /// Rowgroups still accepting inserts in the delta store.
+    pub open_row_groups: i32,
+    /// Full delta rowgroups waiting for the tuple mover.
+    pub closed_row_groups: i32,
+    pub compressed_row_groups: i32,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Type)]
 pub struct IndexInfo {
     pub database_name: String,
@@ -168,6 +711,110 @@ pub struct IndexInfo {
     pub index_name: String,
     pub fragmentation_percent: f64,
     pub page_count: i64,
+    /// `avg_page_space_used_in_percent` — `None` for LIMITED scans.
+    #[serde(default)]
+    pub page_density_percent: Option<f64>,
+    /// `record_count` — `None` for LIMITED scans.
+    #[serde(default)]
+    pub record_count: Option<i64>,
+    /// `ghost_record_count` — `None` for LIMITED scans.
+    #[serde(default)]
+    pub ghost_record_count: Option<i64>,
+    pub partition_number: i32,
+    /// Total partitions of the index; 1 for non-partitioned indexes.
+    pub partition_count: i32,
+    /// `forwarded_record_count` of a heap — `None` for indexes and LIMITED scans.
+    #[serde(default)]
+    pub forwarded_record_count: Option<i64>,
+    #[serde(default)]
+    pub index_kind: IndexKind,
+    /// Index (or, for clustered indexes and heaps, table) has text, ntext or image columns.
+    #[serde(default)]
+    pub has_legacy_lob: bool,
+    /// Index (or table) has varchar(max), nvarchar(max), varbinary(max) or xml columns.
+    #[serde(default)]
+    pub has_max_lob: bool,
+    /// Progress of a paused resumable rebuild of this index; the run resumes it
+    /// instead of deciding from fragmentation.
+    #[serde(default)]
+    pub paused_rebuild_percent: Option<f64>,
+    /// Rowgroup stats for columnstore indexes. Their `fragmentation_percent`
+    /// is the deleted-row ratio, since logical fragmentation does not apply.
+    #[serde(default)]
+    pub columnstore: Option<ColumnstoreStats>,
+    /// Set when the schema, table or index patterns exclude this index; it is
+    /// reported as skipped by policy and never touched.
+    #[serde(default)]
+    pub excluded_by: Option<String>,
+}
+
+impl IndexInfo {
+    /// Partition to target with `PARTITION = n`, or `None` to operate on the whole index.
+    pub fn target_partition(&self) -> Option<i32> {
+        (self.partition_count > 1).then_some(self.partition_number)
+    }
+}
+
+/// A statistics object with its `sys.dm_db_stats_properties` counters.
+#[derive(Debug, Serialize, Deserialize, Clone, Type)]
+pub struct StatisticsInfo {
+    pub schema_name: String,
+    pub table_name: String,
+    pub stats_name: String,
+    /// Statistics of an index, as opposed to auto-created or user-created column statistics.
+    pub is_index_statistics: bool,
+    /// Rows in the table when the statistic was last updated.
+    pub rows: Option<i64>,
+    /// Modifications to the leading column since the last update.
+    pub modification_counter: Option<i64>,
+    pub last_updated: Option<String>,
+    pub days_since_update: Option<i32>,
+}
+
+/// Transaction log usage of the current database, from `sys.dm_db_log_space_usage`.
+#[derive(Debug, Serialize, Deserialize, Clone, Type)]
+pub struct LogSpaceUsage {
+    pub used_percent: f64,
+    pub total_mb: f64,
+    /// `sys.databases.log_reuse_wait_desc`, e.g. `LOG_BACKUP` when only a log
+    /// backup can free space.
+    pub log_reuse_wait: String,
+}
+
+/// Progress of a running request, from `sys.dm_exec_requests`.
+#[derive(Debug, Serialize, Deserialize, Clone, Type)]
+pub struct IndexProgress {
+    /// Zero for commands that do not report it, such as a non-resumable rebuild.
+    pub percent_complete: f64,
+    pub estimated_remaining_secs: f64,
+    /// What the request is waiting on; `None` while it is running.
+    pub wait_type: Option<String>,
+    pub wait_secs: f64,
+    pub cpu_time_ms: i64,
+    pub logical_reads: i64,
+    pub reads: i64,
+    pub writes: i64,
+    pub elapsed_secs: f64,
+}
+
+/// The other end of a blocking chain involving the session running an index
+/// operation.
+#[derive(Debug, Clone)]
+pub struct BlockingSession {
+    pub session_id: i32,
+    pub wait_secs: f64,
+    pub wait_type: String,
+    pub login_name: String,
+    pub host_name: String,
+    pub program_name: String,
+}
+
+/// Blocking around one session: the longest waiter it blocks, and the session
+/// its own request waits on.
+#[derive(Debug, Clone, Default)]
+pub struct BlockingSnapshot {
+    pub blocking: Option<BlockingSession>,
+    pub blocked_by: Option<BlockingSession>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Type)]
@@ -175,6 +822,9 @@ pub struct IndexInfo {
 pub enum MaintenanceAction {
     Rebuild,
     Reorganize,
+    /// `ALTER TABLE ... REBUILD` of a heap to remove forwarded records.
+    #[serde(rename = "REBUILD_HEAP")]
+    RebuildHeap,
     Skip,
 }
 
@@ -182,18 +832,73 @@ pub enum MaintenanceAction {
 // Results
 // ---------------------------------------------------------------------------
 
+/// How an index operation ended.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum IndexOutcome {
+    #[default]
+    Completed,
+    Skipped,
+    Failed,
+    /// Gave up waiting for its lock under `WAIT_AT_LOW_PRIORITY ... ABORT_AFTER_WAIT = SELF`.
+    LowPriorityAborted,
+    /// Not started, or stopped, because the maintenance window closed.
+    Deferred,
+    /// Excluded by the schema, table or index patterns.
+    SkippedByPolicy,
+    /// Cancelled by the blocking monitor for blocking other sessions, or being
+    /// blocked, for too long.
+    BlockingAborted,
+    /// A resumable rebuild left paused server-side, by a stopped run or by its
+    /// own `MAX_DURATION`; the next run resumes it.
+    PausedResumable,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Type)]
 pub struct IndexResult {
     pub schema_name: String,
     pub table_name: String,
     pub index_name: String,
+    /// Partition operated on; `None` when the whole index was processed.
+    #[serde(default)]
+    pub partition_number: Option<i32>,
     pub fragmentation_percent: f64,
     pub page_count: i64,
+    /// Forwarded records found in a heap before it was rebuilt.
+    #[serde(default)]
+    pub forwarded_record_count: Option<i64>,
     pub action: MaintenanceAction,
     pub success: bool,
+    #[serde(default)]
+    pub outcome: IndexOutcome,
+    pub duration_secs: f64,
+    pub retry_attempts: u32,
+    pub error: Option<String>,
+    /// SQL Server error number of `error`, when the server raised it.
+    #[serde(default)]
+    pub error_number: Option<u32>,
+    /// Why the index was handled differently than its thresholds alone suggest,
+    /// e.g. rebuilt offline or skipped because online rebuild is unavailable.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Type)]
+pub struct StatisticsResult {
+    pub schema_name: String,
+    pub table_name: String,
+    pub stats_name: String,
+    pub is_index_statistics: bool,
+    pub rows: Option<i64>,
+    pub modification_counter: Option<i64>,
+    pub last_updated: Option<String>,
+    pub sampling: StatisticsSampling,
+    pub success: bool,
     pub duration_secs: f64,
     pub retry_attempts: u32,
     pub error: Option<String>,
+    /// Which threshold made the statistic due, e.g. the percent of rows modified.
+    pub reason: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Type)]
@@ -204,14 +909,23 @@ pub struct DatabaseResult {
     pub indexes_rebuilt: u32,
     pub indexes_reorganized: u32,
     pub indexes_skipped: u32,
+    #[serde(default)]
+    pub indexes_deferred: u32,
     pub total_duration_secs: f64,
     pub errors: Vec<String>,
     pub critical_failure: bool,
     pub manually_skipped: bool,
+    /// Why the run skipped the database on its own, e.g. an Availability Group secondary.
+    #[serde(default)]
+    pub skip_reason: Option<String>,
     #[serde(default)]
     pub interrupted: bool,
     #[serde(default)]
     pub index_results: Vec<IndexResult>,
+    #[serde(default)]
+    pub statistics_updated: u32,
+    #[serde(default)]
+    pub statistics_results: Vec<StatisticsResult>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Type)]
@@ -222,10 +936,76 @@ pub struct MaintenanceSummary {
     pub total_indexes_rebuilt: u32,
     pub total_indexes_reorganized: u32,
     pub total_indexes_skipped: u32,
+    /// Indexes left for a later run because the maintenance window closed.
+    pub total_indexes_deferred: u32,
     pub total_duration_secs: f64,
+    /// Page-count filters the run was executed with.
+    pub min_page_count: i64,
+    pub max_page_count: i64,
     pub database_results: Vec<DatabaseResult>,
 }
 
+// ---------------------------------------------------------------------------
+// Maintenance plan (dry run — nothing is executed)
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Serialize, Deserialize, Clone, Type)]
+pub struct IndexPlan {
+    pub schema_name: String,
+    pub table_name: String,
+    pub index_name: String,
+    pub partition_number: Option<i32>,
+    pub fragmentation_percent: f64,
+    pub page_count: i64,
+    pub page_density_percent: Option<f64>,
+    pub action: MaintenanceAction,
+    /// ALTER INDEX statement that a run would execute; `None` for SKIP.
+    pub sql: Option<String>,
+    /// UPDATE STATISTICS run right after `sql` when the statistics pass is off
+    /// and the operation does not refresh the index's statistics itself.
+    #[serde(default)]
+    pub statistics_sql: Option<String>,
+    /// Same meaning as [`IndexResult::reason`].
+    pub reason: Option<String>,
+    /// The operation would not fit in what is left of the maintenance window,
+    /// so a run started now would defer it; `reason` says why.
+    #[serde(default)]
+    pub deferred: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Type)]
+pub struct StatisticsPlan {
+    pub schema_name: String,
+    pub table_name: String,
+    pub stats_name: String,
+    pub rows: Option<i64>,
+    pub modification_counter: Option<i64>,
+    pub last_updated: Option<String>,
+    /// UPDATE STATISTICS statement that a run would execute.
+    pub sql: String,
+    /// Same meaning as [`StatisticsResult::reason`].
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Type)]
+pub struct DatabasePlan {
+    pub database_name: String,
+    pub indexes: Vec<IndexPlan>,
+    /// Statistics the statistics pass would update once the index operations are done.
+    pub statistics: Vec<StatisticsPlan>,
+    /// Set when the database could not be analysed (connection or query failure).
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Type)]
+pub struct MaintenancePlan {
+    pub profile_id: String,
+    pub server: String,
+    pub generated_at: String,
+    pub options: MaintenanceOptions,
+    pub database_plans: Vec<DatabasePlan>,
+}
+
 // ---------------------------------------------------------------------------
 // Run history record (returned by history commands)
 // ---------------------------------------------------------------------------
@@ -244,6 +1024,9 @@ pub struct RunRecord {
     pub total_indexes_rebuilt: u32,
     pub total_indexes_reorganized: u32,
     pub total_indexes_skipped: u32,
+    pub total_indexes_deferred: u32,
     pub total_duration_secs: f64,
+    pub min_page_count: i64,
+    pub max_page_count: i64,
     pub database_results: Vec<DatabaseResult>,
 }
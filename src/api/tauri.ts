@@ -1,8 +1,12 @@
 import { invoke } from "@tauri-apps/api/core";
 import type {
+  ConnectionTestResult,
   MaintenanceOptions,
+  MaintenancePlan,
+  PatternFilter,
   RunRecord,
   ServerProfile,
+  UserDatabase,
 } from "../types";
 
 export const getServerProfiles = (): Promise<ServerProfile[]> =>
@@ -15,11 +19,13 @@ export const deleteServerProfile = (id: string): Promise<void> =>
   invoke("delete_server_profile", { id });
 
 // Credentials are loaded server-side — only the profile ID crosses the IPC boundary.
-export const testConnection = (profileId: string): Promise<void> =>
+export const testConnection = (profileId: string): Promise<ConnectionTestResult> =>
   invoke("test_connection", { profileId });
 
-export const getDatabases = (profileId: string): Promise<string[]> =>
-  invoke("get_databases", { profileId });
+export const getDatabases = (
+  profileId: string,
+  databaseFilter: PatternFilter
+): Promise<UserDatabase[]> => invoke("get_databases", { profileId, databaseFilter });
 
 export const runMaintenance = (
   profileId: string,
@@ -27,6 +33,17 @@ export const runMaintenance = (
   options: MaintenanceOptions
 ): Promise<void> => invoke("run_maintenance", { profileId, databases, options });
 
+// Dry run — returns the decisions and T-SQL without executing anything.
+export const planMaintenance = (
+  profileId: string,
+  databases: string[],
+  options: MaintenanceOptions
+): Promise<MaintenancePlan> => invoke("plan_maintenance", { profileId, databases, options });
+
+// Saved to the downloads folder under a generated name; resolves to the file path.
+export const exportMaintenanceScript = (plan: MaintenancePlan): Promise<string> =>
+  invoke("export_maintenance_script", { plan });
+
 export const pauseMaintenance = (profileId: string): Promise<void> =>
   invoke("pause_maintenance", { profileId });
 
@@ -2,7 +2,7 @@ import { X } from "lucide-react";
 import { useRef } from "react";
 import { useDialogA11y } from "../../hooks/useDialogA11y";
 import { useT } from "../../i18n";
-import type { DatabaseCardData, IndexDetail } from "../../types";
+import type { DatabaseCardData, IndexDetail, IndexProgress } from "../../types";
 
 interface Props {
   db: DatabaseCardData;
@@ -11,6 +11,7 @@ interface Props {
 
 const ACTION_BADGE: Record<string, string> = {
   REBUILD: "bg-blue-100 dark:bg-blue-900/60 text-blue-700 dark:text-blue-300 border-blue-200 dark:border-blue-800",
+  REBUILD_HEAP: "bg-indigo-100 dark:bg-indigo-900/60 text-indigo-700 dark:text-indigo-300 border-indigo-200 dark:border-indigo-800",
   REORGANIZE: "bg-purple-100 dark:bg-purple-900/60 text-purple-700 dark:text-purple-300 border-purple-200 dark:border-purple-800",
   SKIP: "bg-gray-100 dark:bg-gray-800 text-gray-700 dark:text-gray-400 border-gray-200 dark:border-gray-700",
 };
@@ -20,6 +21,9 @@ const STATUS_BADGE: Record<string, string> = {
   processing: "bg-yellow-100 dark:bg-yellow-900/60 text-yellow-700 dark:text-yellow-300 animate-pulse",
   done: "bg-green-100 dark:bg-green-900/60 text-green-700 dark:text-green-300",
   skipped: "bg-gray-100 dark:bg-gray-800 text-gray-600 dark:text-gray-500",
+  deferred: "bg-sky-100 dark:bg-sky-900/60 text-sky-700 dark:text-sky-300",
+  paused: "bg-violet-100 dark:bg-violet-900/60 text-violet-700 dark:text-violet-300",
+  aborted: "bg-amber-100 dark:bg-amber-900/60 text-amber-700 dark:text-amber-300",
   error: "bg-red-100 dark:bg-red-900/60 text-red-700 dark:text-red-300",
 };
 
@@ -31,7 +35,39 @@ function Badge({ text, cls }: { text: string; cls: string }) {
   );
 }
 
+function formatSecs(secs: number): string {
+  const s = Math.round(secs);
+  return s >= 60 ? `${Math.floor(s / 60)}m ${s % 60}s` : `${s}s`;
+}
+
+/** One line telling whether a running operation is progressing or stuck. */
+function progressText(p: IndexProgress, t: ReturnType<typeof useT>): string {
+  const parts: string[] = [];
+  if (p.percent_complete > 0) {
+    parts.push(
+      t("drawer.progressPercent", {
+        percent: p.percent_complete.toFixed(1),
+        remaining: formatSecs(p.estimated_remaining_secs),
+      })
+    );
+  }
+  if (p.wait_type) {
+    parts.push(t("drawer.progressWaiting", { wait: p.wait_type, secs: formatSecs(p.wait_secs) }));
+  }
+  parts.push(
+    t("drawer.progressWork", {
+      cpu: formatSecs(p.cpu_time_ms / 1000),
+      reads: p.logical_reads.toLocaleString(),
+      writes: p.writes.toLocaleString(),
+    })
+  );
+  return parts.join(" · ");
+}
+
 function IndexRow({ idx }: { idx: IndexDetail }) {
+  const t = useT();
+  const progress =
+    idx.status === "processing" && idx.progress ? progressText(idx.progress, t) : undefined;
   return (
     <tr className="border-t border-gray-100 dark:border-gray-800 hover:bg-gray-50 dark:hover:bg-gray-800/50">
       <td className="px-3 py-2 text-xs text-gray-700 dark:text-gray-400 font-mono whitespace-nowrap">
@@ -39,6 +75,7 @@ function IndexRow({ idx }: { idx: IndexDetail }) {
       </td>
       <td className="px-3 py-2 text-xs text-gray-700 dark:text-gray-200 font-mono whitespace-nowrap">
         {idx.index_name}
+        {idx.partition_count > 1 && ` (p${idx.partition_number})`}
       </td>
       <td className="px-3 py-2 text-xs text-right">
         <span
@@ -65,7 +102,11 @@ function IndexRow({ idx }: { idx: IndexDetail }) {
         <Badge text={idx.status} cls={STATUS_BADGE[idx.status] ?? ""} />
       </td>
       <td className="px-3 py-2 text-xs text-right text-gray-700 dark:text-gray-400">
-        {idx.duration_secs != null ? `${idx.duration_secs.toFixed(1)}s` : "—"}
+        {idx.duration_secs != null
+          ? `${idx.duration_secs.toFixed(1)}s`
+          : progress
+          ? formatSecs(idx.progress?.elapsed_secs ?? 0)
+          : "—"}
       </td>
       <td className="px-3 py-2 text-xs text-right text-gray-700 dark:text-gray-400">
         {idx.retry_attempts != null && idx.retry_attempts > 1
@@ -73,10 +114,12 @@ function IndexRow({ idx }: { idx: IndexDetail }) {
           : "—"}
       </td>
       <td
-        className="px-3 py-2 text-xs text-red-500 dark:text-red-400 max-w-[320px] break-words"
-        title={idx.error}
+        className={`px-3 py-2 text-xs max-w-[320px] break-words ${
+          idx.error ? "text-red-500 dark:text-red-400" : "text-gray-600 dark:text-gray-400"
+        }`}
+        title={idx.error ?? progress ?? idx.reason}
       >
-        {idx.error ?? "—"}
+        {idx.error ?? progress ?? idx.reason ?? "—"}
       </td>
     </tr>
   );
@@ -166,7 +209,10 @@ export function IndexDetailDrawer({ db, onClose }: Props) {
               </thead>
               <tbody>
                 {db.indexes.map((idx) => (
-                  <IndexRow key={`${idx.schema_name}.${idx.table_name}.${idx.index_name}`} idx={idx} />
+                  <IndexRow
+                    key={`${idx.schema_name}.${idx.table_name}.${idx.index_name}.${idx.partition_number}`}
+                    idx={idx}
+                  />
                 ))}
               </tbody>
             </table>
@@ -141,6 +141,35 @@ export function MaintenanceDashboard() {
       <div className="flex-1 overflow-y-auto">
         <div className="p-4 lg:p-6 pb-32">
           <div className="mx-auto max-w-[1800px] space-y-5">
+            {/* Log usage guard holds */}
+            {Object.values(run.logHolds).map((hold) => (
+              <div
+                key={hold.db_name}
+                role="alert"
+                className="rounded-lg border border-amber-300 dark:border-amber-800 bg-amber-50 dark:bg-amber-950/40 px-4 py-2 text-sm text-amber-800 dark:text-amber-300"
+              >
+                {t(hold.state === "paused" ? "dashboard.logUsagePaused" : "dashboard.logUsageWaiting", {
+                  db: hold.db_name,
+                  used: hold.usage.used_percent.toFixed(1),
+                  max: hold.max_percent,
+                  reuseWait: hold.usage.log_reuse_wait,
+                })}
+              </div>
+            ))}
+            {Object.values(run.agHolds).map((hold) => (
+              <div
+                key={hold.db_name}
+                role="alert"
+                className="rounded-lg border border-amber-300 dark:border-amber-800 bg-amber-50 dark:bg-amber-950/40 px-4 py-2 text-sm text-amber-800 dark:text-amber-300"
+              >
+                {t("dashboard.agThrottle", {
+                  db: hold.db_name,
+                  send: hold.queues.log_send_queue_mb.toFixed(0),
+                  redo: hold.queues.redo_queue_mb.toFixed(0),
+                })}
+              </div>
+            ))}
+
             {/* Stats Cards */}
             {run.summary && (
               <div className="grid grid-cols-2 xl:grid-cols-4 gap-3">
@@ -8,7 +8,9 @@ import { useProfileSettingsStore } from "../../store/profileSettingsStore";
 import { useProfileStore } from "../../store/profileStore";
 import { useUiStore } from "../../store/uiStore";
 import { DEFAULT_OPTIONS } from "../../types";
+import type { MaintenancePlan } from "../../types";
 import { OptionsPanel } from "./OptionsPanel";
+import { PlanPreviewModal } from "./PlanPreviewModal";
 
 export function DatabaseSelector() {
   const t = useT();
@@ -36,12 +38,15 @@ export function DatabaseSelector() {
   const activeProfile = profiles.find((p) => p.id === activeProfileId) ?? null;
   const databases = profileSelection?.databases ?? [];
   const selectedList = profileSelection?.selected ?? [];
+  const replicas = profileSelection?.replicas ?? {};
   const selected = new Set(selectedList);
   const isRunActive =
     runForActiveProfile !== undefined &&
     isActiveRunState(runForActiveProfile.runState);
   const [loading, setLoading] = useState(false);
   const [starting, setStarting] = useState(false);
+  const [planning, setPlanning] = useState(false);
+  const [plan, setPlan] = useState<MaintenancePlan | null>(null);
   const [error, setError] = useState("");
   const [filterQuery, setFilterQuery] = useState("");
 
@@ -63,7 +68,7 @@ export function DatabaseSelector() {
     setLoading(true);
     setError("");
     try {
-      const dbs = await api.getDatabases(activeProfileId);
+      const dbs = await api.getDatabases(activeProfileId, settings.database_filter);
       setDatabasesForProfile(activeProfileId, dbs);
     } catch (e) {
       setError(String(e));
@@ -112,6 +117,20 @@ export function DatabaseSelector() {
     }
   };
 
+  const previewPlan = async () => {
+    if (!activeProfileId || selected.size === 0 || planning) return;
+    const selectedDbs = databases.filter((d) => selected.has(d));
+    setPlanning(true);
+    setError("");
+    try {
+      setPlan(await api.planMaintenance(activeProfileId, selectedDbs, settings));
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setPlanning(false);
+    }
+  };
+
   if (!activeProfile) {
     return (
       <div className="p-6">
@@ -253,6 +272,24 @@ export function DatabaseSelector() {
                       <span className="text-sm text-gray-800 dark:text-gray-200 font-mono truncate">
                         {db}
                       </span>
+                      {replicas[db] && (
+                        <span
+                          title={replicas[db].availability_group ?? t("databases.agUnknownHint")}
+                          className={`ml-auto shrink-0 rounded px-1.5 py-0.5 text-[11px] font-medium ${
+                            replicas[db].replica_role === "primary"
+                              ? "bg-blue-100 text-blue-700 dark:bg-blue-950 dark:text-blue-300"
+                              : replicas[db].replica_role === "unknown"
+                                ? "bg-gray-100 text-gray-700 dark:bg-gray-800 dark:text-gray-400"
+                                : "bg-amber-100 text-amber-700 dark:bg-amber-950 dark:text-amber-300"
+                          }`}
+                        >
+                          {replicas[db].replica_role === "primary"
+                            ? t("databases.agPrimary")
+                            : replicas[db].replica_role === "unknown"
+                              ? t("databases.agUnknown")
+                              : t("databases.agSecondary")}
+                        </span>
+                      )}
                     </label>
                   ))
                 )}
@@ -287,16 +324,28 @@ export function DatabaseSelector() {
                 })}
               </p>
             </div>
-            <button
-              onClick={startMaintenance}
-              disabled={!canStart}
-              className="w-full lg:w-auto lg:min-w-[280px] py-2.5 px-4 bg-green-600 hover:bg-green-500 disabled:bg-gray-200 dark:disabled:bg-gray-700 disabled:text-gray-400 dark:disabled:text-gray-500 text-white font-medium text-sm rounded-lg transition-colors"
-            >
-              {startButtonLabel}
-            </button>
+            <div className="flex flex-col gap-2 lg:flex-row">
+              <button
+                onClick={previewPlan}
+                disabled={selected.size === 0 || planning}
+                className="w-full lg:w-auto inline-flex items-center justify-center gap-2 py-2.5 px-4 bg-gray-200 dark:bg-gray-800 hover:bg-gray-300 dark:hover:bg-gray-700 text-sm text-gray-700 dark:text-gray-300 rounded-lg transition-colors disabled:opacity-50 whitespace-nowrap"
+              >
+                {planning && <Loader2 size={15} className="animate-spin" />}
+                {planning ? t("databases.btnPlanning") : t("databases.btnPreviewPlan")}
+              </button>
+              <button
+                onClick={startMaintenance}
+                disabled={!canStart}
+                className="w-full lg:w-auto lg:min-w-[280px] py-2.5 px-4 bg-green-600 hover:bg-green-500 disabled:bg-gray-200 dark:disabled:bg-gray-700 disabled:text-gray-400 dark:disabled:text-gray-500 text-white font-medium text-sm rounded-lg transition-colors"
+              >
+                {startButtonLabel}
+              </button>
+            </div>
           </div>
         </div>
       </div>
+
+      {plan && <PlanPreviewModal plan={plan} onClose={() => setPlan(null)} />}
     </div>
   );
 }
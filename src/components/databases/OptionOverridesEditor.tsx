@@ -0,0 +1,171 @@
+import { Plus, Trash2 } from "lucide-react";
+import { useT } from "../../i18n";
+import type { DataCompression, OptionOverride } from "../../types";
+
+interface Props {
+  overrides: OptionOverride[];
+  onChange: (overrides: OptionOverride[]) => void;
+}
+
+const inputClass =
+  "w-full bg-white dark:bg-gray-800 border border-gray-300 dark:border-gray-700 rounded-lg px-2 py-1 text-sm text-gray-900 dark:text-white focus:outline-none focus:border-blue-500";
+
+export function OptionOverridesEditor({ overrides, onChange }: Props) {
+  const t = useT();
+
+  const update = (index: number, patch: Partial<OptionOverride>) =>
+    onChange(overrides.map((o, i) => (i === index ? { ...o, ...patch } : o)));
+  const remove = (index: number) => onChange(overrides.filter((_, i) => i !== index));
+  const add = () => onChange([...overrides, { database: "", table: "" }]);
+
+  return (
+    <div className="space-y-3">
+      {overrides.map((o, i) => (
+        <div
+          key={i}
+          className="rounded-lg border border-gray-200 dark:border-gray-800 p-3 space-y-2"
+        >
+          <div className="flex items-center gap-2">
+            <input
+              value={o.database}
+              placeholder={t("options.overrideDatabase")}
+              onChange={(e) => update(i, { database: e.target.value })}
+              className={`${inputClass} font-mono`}
+            />
+            <input
+              value={o.table}
+              placeholder={t("options.overrideTable")}
+              onChange={(e) => update(i, { table: e.target.value })}
+              className={`${inputClass} font-mono`}
+            />
+            <button
+              onClick={() => remove(i)}
+              className="p-1.5 text-gray-400 hover:text-red-500 transition-colors"
+              title={t("options.overrideRemove")}
+            >
+              <Trash2 size={15} />
+            </button>
+          </div>
+          <div className="grid grid-cols-2 gap-2 sm:grid-cols-4">
+            <NumberField
+              label={t("options.overrideReorganizeThreshold")}
+              value={o.reorganize_threshold}
+              max={99}
+              onChange={(v) => update(i, { reorganize_threshold: v })}
+            />
+            <NumberField
+              label={t("options.overrideRebuildThreshold")}
+              value={o.rebuild_threshold}
+              max={99}
+              onChange={(v) => update(i, { rebuild_threshold: v })}
+            />
+            <NumberField
+              label={t("options.overrideMaxdop")}
+              value={o.maxdop}
+              onChange={(v) => update(i, { maxdop: v })}
+            />
+            <NumberField
+              label={t("options.overrideFillFactor")}
+              value={o.fill_factor}
+              max={100}
+              onChange={(v) => update(i, { fill_factor: v })}
+            />
+            <BooleanField
+              label={t("options.overrideOnline")}
+              value={o.rebuild_online}
+              onChange={(v) => update(i, { rebuild_online: v })}
+            />
+            <BooleanField
+              label={t("options.overrideReorganizeOnly")}
+              value={o.reorganize_only}
+              onChange={(v) => update(i, { reorganize_only: v })}
+            />
+            <label className="flex flex-col gap-1 text-xs text-gray-600 dark:text-gray-500">
+              {t("options.overrideCompression")}
+              <select
+                value={o.data_compression ?? ""}
+                onChange={(e) =>
+                  update(i, {
+                    data_compression: e.target.value === "" ? null : (e.target.value as DataCompression),
+                  })
+                }
+                className={inputClass}
+              >
+                <option value="">{t("options.overrideInherit")}</option>
+                <option value="KEEP">{t("options.keepExisting")}</option>
+                <option value="NONE">NONE</option>
+                <option value="ROW">ROW</option>
+                <option value="PAGE">PAGE</option>
+              </select>
+            </label>
+          </div>
+        </div>
+      ))}
+      <button
+        onClick={add}
+        className="flex items-center gap-2 text-sm text-blue-600 dark:text-blue-400 hover:text-blue-500"
+      >
+        <Plus size={14} />
+        {t("options.overrideAdd")}
+      </button>
+    </div>
+  );
+}
+
+/** Empty input = inherit the run-wide value. */
+function NumberField({
+  label,
+  value,
+  max,
+  onChange,
+}: {
+  label: string;
+  value?: number | null;
+  max?: number;
+  onChange: (v: number | null) => void;
+}) {
+  return (
+    <label className="flex flex-col gap-1 text-xs text-gray-600 dark:text-gray-500">
+      {label}
+      <input
+        type="number"
+        value={value ?? ""}
+        min={0}
+        max={max}
+        onChange={(e) => {
+          const raw = e.target.value;
+          if (raw === "") return onChange(null);
+          const n = Math.max(Number(raw) || 0, 0);
+          onChange(max != null ? Math.min(n, max) : n);
+        }}
+        className={`${inputClass} text-right`}
+      />
+    </label>
+  );
+}
+
+function BooleanField({
+  label,
+  value,
+  onChange,
+}: {
+  label: string;
+  value?: boolean | null;
+  onChange: (v: boolean | null) => void;
+}) {
+  const t = useT();
+  return (
+    <label className="flex flex-col gap-1 text-xs text-gray-600 dark:text-gray-500">
+      {label}
+      <select
+        value={value == null ? "" : value ? "on" : "off"}
+        onChange={(e) => onChange(e.target.value === "" ? null : e.target.value === "on")}
+        className={inputClass}
+      >
+        <option value="">{t("options.overrideInherit")}</option>
+        <option value="on">{t("options.overrideOn")}</option>
+        <option value="off">{t("options.overrideOff")}</option>
+      </select>
+    </label>
+  );
+}
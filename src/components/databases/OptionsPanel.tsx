@@ -1,5 +1,7 @@
+import { useEffect, useState } from "react";
 import { useT } from "../../i18n";
-import type { MaintenanceOptions } from "../../types";
+import type { MaintenanceOptions, PatternFilter } from "../../types";
+import { OptionOverridesEditor } from "./OptionOverridesEditor";
 
 interface Props {
   settings: MaintenanceOptions;
@@ -36,6 +38,31 @@ export function OptionsPanel({ settings, onChange }: Props) {
         />
       </div>
 
+      <div className="border-b border-gray-200 dark:border-gray-800 pb-4 space-y-3">
+        <h4 className="text-xs font-medium text-gray-600 dark:text-gray-500 uppercase tracking-wide">
+          {t("options.session")}
+        </h4>
+        <TextOption
+          label={t("options.applicationName")}
+          hint={t("options.applicationNameHint")}
+          value={settings.application_name}
+          maxLength={128}
+          onChange={(v) => set("application_name", v)}
+        />
+        <NumberOption
+          label={t("options.lockTimeout")}
+          value={settings.lock_timeout_ms}
+          onChange={(v) => set("lock_timeout_ms", v)}
+          hint={t("options.lockTimeoutHint")}
+        />
+        <CheckboxOption
+          label={t("options.deadlockPriorityLow")}
+          description={t("options.deadlockPriorityLowDesc")}
+          checked={settings.deadlock_priority_low}
+          onChange={(v) => set("deadlock_priority_low", v)}
+        />
+      </div>
+
       <div className="border-b border-gray-200 dark:border-gray-800 pb-4 space-y-3">
         <h4 className="text-xs font-medium text-gray-600 dark:text-gray-500 uppercase tracking-wide">
           {t("options.thresholds")}
@@ -59,6 +86,148 @@ export function OptionsPanel({ settings, onChange }: Props) {
             {t("options.thresholdHint")}
           </p>
         )}
+        <CheckboxOption
+          label={t("options.reorganizeOnly")}
+          description={t("options.reorganizeOnlyDesc")}
+          checked={settings.reorganize_only}
+          onChange={(v) => set("reorganize_only", v)}
+        />
+        <SelectOption
+          label={t("options.scanMode")}
+          hint={t("options.scanModeDesc")}
+          value={settings.scan_mode}
+          options={["LIMITED", "SAMPLED", "DETAILED"] as const}
+          onChange={(v) => set("scan_mode", v)}
+        />
+        <NumberOption
+          label={t("options.pageDensityThreshold")}
+          value={settings.page_density_threshold}
+          onChange={(v) => set("page_density_threshold", v)}
+          hint={t("options.pageDensityThresholdHint")}
+          max={100}
+        />
+        <NumberOption
+          label={t("options.minPageCount")}
+          value={settings.min_page_count}
+          onChange={(v) => set("min_page_count", v)}
+        />
+        <NumberOption
+          label={t("options.maxPageCount")}
+          value={settings.max_page_count}
+          onChange={(v) => set("max_page_count", v)}
+          hint={t("options.noLimit")}
+        />
+      </div>
+
+      <div className="border-b border-gray-200 dark:border-gray-800 pb-4 space-y-3">
+        <h4 className="text-xs font-medium text-gray-600 dark:text-gray-500 uppercase tracking-wide">
+          {t("options.columnstore")}
+        </h4>
+        <NumberOption
+          label={t("options.columnstoreReorganizeThreshold")}
+          value={settings.columnstore_reorganize_threshold}
+          onChange={(v) => set("columnstore_reorganize_threshold", v)}
+          min={1}
+          max={99}
+        />
+        <NumberOption
+          label={t("options.columnstoreRebuildThreshold")}
+          value={settings.columnstore_rebuild_threshold}
+          onChange={(v) => set("columnstore_rebuild_threshold", v)}
+          min={1}
+          max={99}
+        />
+        <NumberOption
+          label={t("options.columnstoreDeltaRowgroupThreshold")}
+          value={settings.columnstore_delta_rowgroup_threshold}
+          onChange={(v) => set("columnstore_delta_rowgroup_threshold", v)}
+          hint={t("options.columnstoreDeltaRowgroupHint")}
+        />
+      </div>
+
+      <div className="border-b border-gray-200 dark:border-gray-800 pb-4 space-y-3">
+        <h4 className="text-xs font-medium text-gray-600 dark:text-gray-500 uppercase tracking-wide">
+          {t("options.heaps")}
+        </h4>
+        <CheckboxOption
+          label={t("options.includeHeaps")}
+          description={t("options.includeHeapsDesc")}
+          checked={settings.include_heaps}
+          onChange={(v) => set("include_heaps", v)}
+        />
+        {settings.include_heaps && (
+          <>
+            <NumberOption
+              label={t("options.heapForwardedRecordThreshold")}
+              value={settings.heap_forwarded_record_threshold}
+              onChange={(v) => set("heap_forwarded_record_threshold", v)}
+              hint={t("options.heapForwardedRecordHint")}
+              max={100}
+            />
+            <NumberOption
+              label={t("options.heapFragmentationThreshold")}
+              value={settings.heap_fragmentation_threshold}
+              onChange={(v) => set("heap_fragmentation_threshold", v)}
+              hint={t("options.disabledWhenZero")}
+              max={100}
+            />
+          </>
+        )}
+      </div>
+
+      <div className="border-b border-gray-200 dark:border-gray-800 pb-4 space-y-3">
+        <h4 className="text-xs font-medium text-gray-600 dark:text-gray-500 uppercase tracking-wide">
+          {t("options.statistics")}
+        </h4>
+        <CheckboxOption
+          label={t("options.updateStatistics")}
+          description={t("options.updateStatisticsDesc")}
+          checked={settings.update_statistics}
+          onChange={(v) => set("update_statistics", v)}
+        />
+        {settings.update_statistics && (
+          <>
+            <CheckboxOption
+              label={t("options.includeColumnStatistics")}
+              description={t("options.includeColumnStatisticsDesc")}
+              checked={settings.include_column_statistics}
+              onChange={(v) => set("include_column_statistics", v)}
+            />
+            <NumberOption
+              label={t("options.statisticsModificationThreshold")}
+              value={settings.statistics_modification_threshold}
+              onChange={(v) => set("statistics_modification_threshold", v)}
+              hint={t("options.disabledWhenZero")}
+            />
+            <NumberOption
+              label={t("options.statisticsMaxAgeDays")}
+              value={settings.statistics_max_age_days}
+              onChange={(v) => set("statistics_max_age_days", v)}
+              hint={t("options.disabledWhenZero")}
+            />
+            <SelectOption
+              label={t("options.statisticsSampling")}
+              value={settings.statistics_sampling}
+              options={["DEFAULT", "SAMPLE", "RESAMPLE", "FULLSCAN"] as const}
+              labels={{
+                DEFAULT: t("options.statisticsSamplingDefault"),
+                SAMPLE: t("options.statisticsSamplingSample"),
+                RESAMPLE: "RESAMPLE",
+                FULLSCAN: "FULLSCAN",
+              }}
+              onChange={(v) => set("statistics_sampling", v)}
+            />
+            {settings.statistics_sampling === "SAMPLE" && (
+              <NumberOption
+                label={t("options.statisticsSamplePercent")}
+                value={settings.statistics_sample_percent}
+                onChange={(v) => set("statistics_sample_percent", v)}
+                min={1}
+                max={100}
+              />
+            )}
+          </>
+        )}
       </div>
 
       <CheckboxOption
@@ -67,6 +236,98 @@ export function OptionsPanel({ settings, onChange }: Props) {
         checked={settings.rebuild_online}
         onChange={(v) => set("rebuild_online", v)}
       />
+      {settings.rebuild_online && (
+        <SelectOption
+          label={t("options.onlineFallback")}
+          hint={t("options.onlineFallbackDesc")}
+          value={settings.online_fallback}
+          options={["offline", "skip"] as const}
+          labels={{
+            offline: t("options.onlineFallbackOffline"),
+            skip: t("options.onlineFallbackSkip"),
+          }}
+          onChange={(v) => set("online_fallback", v)}
+        />
+      )}
+
+      {settings.rebuild_online && (
+        <CheckboxOption
+          label={t("options.resumableRebuild")}
+          description={t("options.resumableRebuildDesc")}
+          checked={settings.resumable_rebuild}
+          onChange={(v) => set("resumable_rebuild", v)}
+        />
+      )}
+      {settings.rebuild_online && (
+        <CheckboxOption
+          label={t("options.lowPriorityWait")}
+          description={t("options.lowPriorityWaitDesc")}
+          checked={settings.low_priority_wait}
+          onChange={(v) => set("low_priority_wait", v)}
+        />
+      )}
+      {settings.rebuild_online && settings.low_priority_wait && (
+        <>
+          <NumberOption
+            label={t("options.lowPriorityMaxDuration")}
+            value={settings.low_priority_max_duration_minutes}
+            onChange={(v) => set("low_priority_max_duration_minutes", v)}
+            min={1}
+          />
+          <SelectOption
+            label={t("options.abortAfterWait")}
+            value={settings.abort_after_wait}
+            options={["NONE", "SELF", "BLOCKERS"] as const}
+            onChange={(v) => set("abort_after_wait", v)}
+          />
+        </>
+      )}
+      {settings.rebuild_online && settings.resumable_rebuild && (
+        <NumberOption
+          label={t("options.resumableMaxDuration")}
+          value={settings.resumable_max_duration_minutes}
+          onChange={(v) => set("resumable_max_duration_minutes", v)}
+          hint={t("options.noLimit")}
+        />
+      )}
+
+      <div className="border-y border-gray-200 dark:border-gray-800 py-4 space-y-3">
+        <h4 className="text-xs font-medium text-gray-600 dark:text-gray-500 uppercase tracking-wide">
+          {t("options.rebuildSettings")}
+        </h4>
+        <NumberOption
+          label={t("options.maxdop")}
+          value={settings.maxdop}
+          onChange={(v) => set("maxdop", v)}
+          hint={t("options.serverDefault")}
+        />
+        <NumberOption
+          label={t("options.fillFactor")}
+          value={settings.fill_factor}
+          onChange={(v) => set("fill_factor", v)}
+          hint={t("options.fillFactorHint")}
+          max={100}
+        />
+        <SelectOption
+          label={t("options.dataCompression")}
+          value={settings.data_compression}
+          options={["KEEP", "NONE", "ROW", "PAGE"] as const}
+          labels={{ KEEP: t("options.keepExisting"), NONE: "NONE", ROW: "ROW", PAGE: "PAGE" }}
+          onChange={(v) => set("data_compression", v)}
+        />
+        <CheckboxOption
+          label={t("options.padIndex")}
+          description={t("options.padIndexDesc")}
+          checked={settings.pad_index}
+          onChange={(v) => set("pad_index", v)}
+        />
+        <CheckboxOption
+          label={t("options.sortInTempdb")}
+          description={t("options.sortInTempdbDesc")}
+          checked={settings.sort_in_tempdb}
+          onChange={(v) => set("sort_in_tempdb", v)}
+        />
+      </div>
 
       <CheckboxOption
         label={t("options.freeProcCache")}
@@ -95,6 +356,164 @@ export function OptionsPanel({ settings, onChange }: Props) {
           value={settings.retry_max_delay_ms}
           onChange={(v) => set("retry_max_delay_ms", v)}
         />
+        <NumberListOption
+          label={t("options.retryableErrors")}
+          hint={t("options.retryableErrorsHint")}
+          value={settings.retryable_error_numbers}
+          onChange={(v) => set("retryable_error_numbers", v)}
+        />
+        <CheckboxOption
+          label={t("options.retryTimeouts")}
+          description={t("options.retryTimeoutsDesc")}
+          checked={settings.retry_timeouts}
+          onChange={(v) => set("retry_timeouts", v)}
+        />
+        <CheckboxOption
+          label={t("options.retryConnectionErrors")}
+          description={t("options.retryConnectionErrorsDesc")}
+          checked={settings.retry_connection_errors}
+          onChange={(v) => set("retry_connection_errors", v)}
+        />
+      </div>
+
+      <div className="border-t border-gray-200 dark:border-gray-800 pt-4 space-y-3">
+        <h4 className="text-xs font-medium text-gray-600 dark:text-gray-500 uppercase tracking-wide">
+          {t("options.objectFilters")}
+        </h4>
+        <p className="text-xs text-gray-600 dark:text-gray-500">{t("options.objectFiltersHint")}</p>
+        <PatternOption
+          label={t("options.databaseFilter")}
+          value={settings.database_filter}
+          onChange={(v) => set("database_filter", v)}
+        />
+        <PatternOption
+          label={t("options.schemaFilter")}
+          value={settings.schema_filter}
+          onChange={(v) => set("schema_filter", v)}
+        />
+        <PatternOption
+          label={t("options.tableFilter")}
+          hint={t("options.tableFilterHint")}
+          value={settings.table_filter}
+          onChange={(v) => set("table_filter", v)}
+        />
+        <PatternOption
+          label={t("options.indexFilter")}
+          value={settings.index_filter}
+          onChange={(v) => set("index_filter", v)}
+        />
+      </div>
+
+      <div className="border-t border-gray-200 dark:border-gray-800 pt-4 space-y-3">
+        <h4 className="text-xs font-medium text-gray-600 dark:text-gray-500 uppercase tracking-wide">
+          {t("options.overrides")}
+        </h4>
+        <p className="text-xs text-gray-600 dark:text-gray-500">{t("options.overridesHint")}</p>
+        <OptionOverridesEditor
+          overrides={settings.overrides}
+          onChange={(v) => set("overrides", v)}
+        />
+      </div>
+
+      <div className="border-t border-gray-200 dark:border-gray-800 pt-4 space-y-3">
+        <h4 className="text-xs font-medium text-gray-600 dark:text-gray-500 uppercase tracking-wide">
+          {t("options.maintenanceWindow")}
+        </h4>
+        <SelectOption
+          label={t("options.indexPriority")}
+          hint={t("options.indexPriorityHint")}
+          value={settings.index_priority}
+          options={
+            [
+              "fragmentation",
+              "largestBenefit",
+              "mostUsed",
+              "smallestFirst",
+              "leastRecentlyMaintained",
+            ] as const
+          }
+          labels={{
+            fragmentation: t("options.priorityFragmentation"),
+            largestBenefit: t("options.priorityLargestBenefit"),
+            mostUsed: t("options.priorityMostUsed"),
+            smallestFirst: t("options.prioritySmallestFirst"),
+            leastRecentlyMaintained: t("options.priorityLeastRecentlyMaintained"),
+          }}
+          onChange={(v) => set("index_priority", v)}
+        />
+        <TimeOption
+          label={t("options.windowEndTime")}
+          hint={t("options.windowEndTimeHint")}
+          value={settings.window_end_time}
+          onChange={(v) => set("window_end_time", v)}
+        />
+        <NumberOption
+          label={t("options.maxRunMinutes")}
+          value={settings.max_run_minutes}
+          onChange={(v) => set("max_run_minutes", v)}
+          hint={t("options.noLimit")}
+        />
+      </div>
+
+      <div className="border-t border-gray-200 dark:border-gray-800 pt-4 space-y-3">
+        <h4 className="text-xs font-medium text-gray-600 dark:text-gray-500 uppercase tracking-wide">
+          {t("options.logUsage")}
+        </h4>
+        <NumberOption
+          label={t("options.logUsageMaxPercent")}
+          value={settings.log_usage_max_percent}
+          onChange={(v) => set("log_usage_max_percent", v)}
+          hint={t("options.disabledWhenZero")}
+          max={100}
+        />
+        {settings.log_usage_max_percent > 0 && (
+          <SelectOption
+            label={t("options.logUsageAction")}
+            hint={t("options.logUsageActionHint")}
+            value={settings.log_usage_action}
+            options={["wait", "pause"] as const}
+            labels={{ wait: t("options.logUsageWait"), pause: t("options.logUsagePause") }}
+            onChange={(v) => set("log_usage_action", v)}
+          />
+        )}
+      </div>
+
+      <div className="border-t border-gray-200 dark:border-gray-800 pt-4 space-y-3">
+        <h4 className="text-xs font-medium text-gray-600 dark:text-gray-500 uppercase tracking-wide">
+          {t("options.availabilityGroups")}
+        </h4>
+        <p className="text-xs text-gray-600 dark:text-gray-500">{t("options.availabilityGroupsHint")}</p>
+        <NumberOption
+          label={t("options.agMaxLogSendQueue")}
+          value={settings.ag_max_log_send_queue_mb}
+          onChange={(v) => set("ag_max_log_send_queue_mb", v)}
+          hint={t("options.disabledWhenZero")}
+        />
+        <NumberOption
+          label={t("options.agMaxRedoQueue")}
+          value={settings.ag_max_redo_queue_mb}
+          onChange={(v) => set("ag_max_redo_queue_mb", v)}
+          hint={t("options.disabledWhenZero")}
+        />
+      </div>
+
+      <div className="border-t border-gray-200 dark:border-gray-800 pt-4 space-y-3">
+        <h4 className="text-xs font-medium text-gray-600 dark:text-gray-500 uppercase tracking-wide">
+          {t("options.blockingMonitor")}
+        </h4>
+        <p className="text-xs text-gray-600 dark:text-gray-500">{t("options.blockingMonitorHint")}</p>
+        <NumberOption
+          label={t("options.maxBlockingSecs")}
+          value={settings.max_blocking_secs}
+          onChange={(v) => set("max_blocking_secs", v)}
+          hint={t("options.disabledWhenZero")}
+        />
+        <NumberOption
+          label={t("options.maxBlockedSecs")}
+          value={settings.max_blocked_secs}
+          onChange={(v) => set("max_blocked_secs", v)}
+          hint={t("options.disabledWhenZero")}
+        />
       </div>
 
       <div className="border-t border-gray-200 dark:border-gray-800 pt-4 space-y-3">
@@ -185,3 +604,173 @@ function NumberOption({
     </div>
   );
 }
+
+function TimeOption({
+  label,
+  hint,
+  value,
+  onChange,
+}: {
+  label: string;
+  hint?: string;
+  value: string;
+  onChange: (v: string) => void;
+}) {
+  return (
+    <div className="flex flex-col gap-1.5 sm:flex-row sm:items-center sm:justify-between">
+      <div>
+        <label className="text-sm text-gray-700 dark:text-gray-300">{label}</label>
+        {hint && <p className="text-xs text-gray-600 dark:text-gray-500">{hint}</p>}
+      </div>
+      <input
+        type="time"
+        value={value}
+        onChange={(e) => onChange(e.target.value)}
+        className="w-full sm:w-28 bg-white dark:bg-gray-800 border border-gray-300 dark:border-gray-700 rounded-lg px-2 py-1 text-sm text-gray-900 dark:text-white text-right focus:outline-none focus:border-blue-500"
+      />
+    </div>
+  );
+}
+
+function TextOption({
+  label,
+  hint,
+  value,
+  maxLength,
+  onChange,
+}: {
+  label: string;
+  hint?: string;
+  value: string;
+  maxLength?: number;
+  onChange: (v: string) => void;
+}) {
+  return (
+    <div className="flex flex-col gap-1.5 sm:flex-row sm:items-center sm:justify-between">
+      <div>
+        <label className="text-sm text-gray-700 dark:text-gray-300">{label}</label>
+        {hint && <p className="text-xs text-gray-600 dark:text-gray-500">{hint}</p>}
+      </div>
+      <input
+        type="text"
+        value={value}
+        maxLength={maxLength}
+        onChange={(e) => onChange(e.target.value)}
+        className="w-full sm:w-40 bg-white dark:bg-gray-800 border border-gray-300 dark:border-gray-700 rounded-lg px-2 py-1 text-sm text-gray-900 dark:text-white focus:outline-none focus:border-blue-500"
+      />
+    </div>
+  );
+}
+
+/** Comma- or space-separated numbers, parsed when the field loses focus. */
+function NumberListOption({
+  label,
+  hint,
+  value,
+  onChange,
+}: {
+  label: string;
+  hint?: string;
+  value: number[];
+  onChange: (v: number[]) => void;
+}) {
+  const formatted = value.join(", ");
+  const [draft, setDraft] = useState(formatted);
+  useEffect(() => setDraft(formatted), [formatted]);
+
+  const commit = () => {
+    const numbers = draft
+      .split(/[\s,]+/)
+      .map(Number)
+      .filter((n) => Number.isInteger(n) && n > 0);
+    const unique = Array.from(new Set(numbers));
+    onChange(unique);
+    setDraft(unique.join(", "));
+  };
+
+  return (
+    <div className="flex flex-col gap-1.5">
+      <label className="text-sm text-gray-700 dark:text-gray-300">{label}</label>
+      {hint && <p className="text-xs text-gray-600 dark:text-gray-500">{hint}</p>}
+      <input
+        type="text"
+        value={draft}
+        onChange={(e) => setDraft(e.target.value)}
+        onBlur={commit}
+        className="w-full bg-white dark:bg-gray-800 border border-gray-300 dark:border-gray-700 rounded-lg px-2 py-1 text-sm font-mono text-gray-900 dark:text-white focus:outline-none focus:border-blue-500"
+      />
+    </div>
+  );
+}
+
+function PatternOption({
+  label,
+  hint,
+  value,
+  onChange,
+}: {
+  label: string;
+  hint?: string;
+  value: PatternFilter;
+  onChange: (v: PatternFilter) => void;
+}) {
+  const t = useT();
+  const field = (key: keyof PatternFilter, placeholder: string) => (
+    <textarea
+      rows={2}
+      value={value[key].join("\n")}
+      placeholder={placeholder}
+      onChange={(e) => onChange({ ...value, [key]: e.target.value.split("\n") })}
+      className="w-full bg-white dark:bg-gray-800 border border-gray-300 dark:border-gray-700 rounded-lg px-2 py-1 text-xs font-mono text-gray-900 dark:text-white focus:outline-none focus:border-blue-500"
+    />
+  );
+
+  return (
+    <div className="space-y-1.5">
+      <div>
+        <label className="text-sm text-gray-700 dark:text-gray-300">{label}</label>
+        {hint && <p className="text-xs text-gray-600 dark:text-gray-500">{hint}</p>}
+      </div>
+      <div className="grid grid-cols-1 gap-2 sm:grid-cols-2">
+        {field("include", t("options.filterInclude"))}
+        {field("exclude", t("options.filterExclude"))}
+      </div>
+    </div>
+  );
+}
+
+function SelectOption<T extends string>({
+  label,
+  hint,
+  value,
+  options,
+  labels,
+  onChange,
+}: {
+  label: string;
+  hint?: string;
+  value: T;
+  options: readonly T[];
+  labels?: Record<T, string>;
+  onChange: (v: T) => void;
+}) {
+  return (
+    <div className="flex flex-col gap-1.5 sm:flex-row sm:items-center sm:justify-between">
+      <div>
+        <label className="text-sm text-gray-700 dark:text-gray-300">{label}</label>
+        {hint && <p className="text-xs text-gray-600 dark:text-gray-500">{hint}</p>}
+      </div>
+      <select
+        value={value}
+        onChange={(e) => onChange(e.target.value as T)}
+        className="w-full sm:w-28 bg-white dark:bg-gray-800 border border-gray-300 dark:border-gray-700 rounded-lg px-2 py-1 text-sm text-gray-900 dark:text-white focus:outline-none focus:border-blue-500"
+      >
+        {options.map((o) => (
+          <option key={o} value={o}>
+            {labels?.[o] ?? o}
+          </option>
+        ))}
+      </select>
+    </div>
+  );
+}
@@ -0,0 +1,228 @@
+import { Download, Loader2, X } from "lucide-react";
+import { useRef, useState } from "react";
+import * as api from "../../api/tauri";
+import { useDialogA11y } from "../../hooks/useDialogA11y";
+import { useT } from "../../i18n";
+import type { DatabasePlan, IndexPlan, MaintenancePlan } from "../../types";
+
+interface Props {
+  plan: MaintenancePlan;
+  onClose: () => void;
+}
+
+const ACTION_BADGE: Record<string, string> = {
+  REBUILD: "bg-blue-100 dark:bg-blue-900/60 text-blue-700 dark:text-blue-300 border-blue-200 dark:border-blue-800",
+  REBUILD_HEAP: "bg-indigo-100 dark:bg-indigo-900/60 text-indigo-700 dark:text-indigo-300 border-indigo-200 dark:border-indigo-800",
+  REORGANIZE: "bg-purple-100 dark:bg-purple-900/60 text-purple-700 dark:text-purple-300 border-purple-200 dark:border-purple-800",
+  SKIP: "bg-gray-100 dark:bg-gray-800 text-gray-700 dark:text-gray-400 border-gray-200 dark:border-gray-700",
+};
+
+const DEFERRED_BADGE =
+  "bg-sky-100 dark:bg-sky-900/60 text-sky-700 dark:text-sky-300 border-sky-200 dark:border-sky-800";
+
+function Badge({ text, cls }: { text: string; cls: string }) {
+  return (
+    <span className={`inline-flex px-1.5 py-0.5 rounded text-xs border ${cls}`}>
+      {text}
+    </span>
+  );
+}
+
+function IndexPlanRow({ idx }: { idx: IndexPlan }) {
+  const t = useT();
+  return (
+    <tr className="border-t border-gray-100 dark:border-gray-800 align-top">
+      <td className="px-3 py-2 text-xs text-gray-700 dark:text-gray-400 font-mono whitespace-nowrap">
+        {idx.schema_name}.{idx.table_name}
+      </td>
+      <td className="px-3 py-2 text-xs text-gray-700 dark:text-gray-200 font-mono whitespace-nowrap">
+        {idx.index_name}
+        {idx.partition_number != null && ` (p${idx.partition_number})`}
+      </td>
+      <td className="px-3 py-2 text-xs text-right text-gray-700 dark:text-gray-400">
+        {idx.fragmentation_percent.toFixed(1)}%
+      </td>
+      <td className="px-3 py-2 text-xs text-right text-gray-700 dark:text-gray-400">
+        {idx.page_count.toLocaleString()}
+      </td>
+      <td className="px-3 py-2 space-x-1 whitespace-nowrap">
+        <Badge text={idx.action} cls={ACTION_BADGE[idx.action] ?? ""} />
+        {idx.deferred && <Badge text={t("plan.deferred")} cls={DEFERRED_BADGE} />}
+      </td>
+      <td className="px-3 py-2 text-xs text-gray-600 dark:text-gray-400 max-w-[420px] break-words">
+        {idx.reason && <p>{idx.reason}</p>}
+        {idx.sql && (
+          <code className="block font-mono text-[11px] text-gray-500 dark:text-gray-500 break-all">
+            {idx.sql}
+          </code>
+        )}
+        {idx.statistics_sql && (
+          <code className="block font-mono text-[11px] text-gray-500 dark:text-gray-500 break-all">
+            {idx.statistics_sql}
+          </code>
+        )}
+        {!idx.reason && !idx.sql && "—"}
+      </td>
+    </tr>
+  );
+}
+
+function DatabasePlanSection({ db }: { db: DatabasePlan }) {
+  const t = useT();
+  const operations = db.indexes.filter((i) => i.action !== "SKIP");
+  const deferred = operations.filter((i) => i.deferred).length;
+  const headers = [
+    t("drawer.colSchemaTable"),
+    t("drawer.colIndex"),
+    t("drawer.colFrag"),
+    t("drawer.colPages"),
+    t("drawer.colAction"),
+    t("plan.colDetail"),
+  ];
+
+  return (
+    <section className="border-b border-gray-200 dark:border-gray-800">
+      <div className="px-5 py-3 flex items-baseline justify-between gap-3">
+        <h4 className="font-medium text-sm text-gray-900 dark:text-white">{db.database_name}</h4>
+        <p className="text-xs text-gray-600 dark:text-gray-500">
+          {t("plan.dbSummary", {
+            operations: operations.length - deferred,
+            deferred,
+            skipped: db.indexes.length - operations.length,
+            statistics: db.statistics.length,
+          })}
+        </p>
+      </div>
+      {db.error && (
+        <p className="px-5 pb-3 text-xs text-red-600 dark:text-red-300 break-words">{db.error}</p>
+      )}
+      {db.indexes.length > 0 && (
+        <table className="w-full min-w-[900px]">
+          <thead className="bg-gray-50 dark:bg-gray-800/40">
+            <tr>
+              {headers.map((h) => (
+                <th
+                  key={h}
+                  className="px-3 py-2 text-left text-xs font-medium text-gray-600 dark:text-gray-500 uppercase tracking-wide"
+                >
+                  {h}
+                </th>
+              ))}
+            </tr>
+          </thead>
+          <tbody>
+            {db.indexes.map((idx) => (
+              <IndexPlanRow
+                key={`${idx.schema_name}.${idx.table_name}.${idx.index_name}.${idx.partition_number}`}
+                idx={idx}
+              />
+            ))}
+          </tbody>
+        </table>
+      )}
+      {db.statistics.length > 0 && (
+        <div className="px-5 py-3 space-y-1">
+          <p className="text-xs font-medium text-gray-600 dark:text-gray-500 uppercase tracking-wide">
+            {t("plan.statistics", { count: db.statistics.length })}
+          </p>
+          {db.statistics.map((stat) => (
+            <p
+              key={`${stat.schema_name}.${stat.table_name}.${stat.stats_name}`}
+              className="text-xs text-gray-700 dark:text-gray-400 font-mono break-all"
+              title={stat.reason}
+            >
+              {stat.sql}
+            </p>
+          ))}
+        </div>
+      )}
+    </section>
+  );
+}
+
+export function PlanPreviewModal({ plan, onClose }: Props) {
+  const t = useT();
+  const dialogRef = useRef<HTMLDivElement>(null);
+  useDialogA11y(dialogRef, onClose);
+  const [exporting, setExporting] = useState(false);
+  const [exported, setExported] = useState("");
+  const [error, setError] = useState("");
+
+  const exportScript = async () => {
+    setExporting(true);
+    setError("");
+    try {
+      setExported(await api.exportMaintenanceScript(plan));
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setExporting(false);
+    }
+  };
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-end justify-center bg-black/50 backdrop-blur-sm"
+      onClick={onClose}
+    >
+      <div
+        ref={dialogRef}
+        role="dialog"
+        aria-modal="true"
+        aria-labelledby="plan-preview-title"
+        tabIndex={-1}
+        className="bg-white dark:bg-gray-900 border border-gray-200 dark:border-gray-700 rounded-t-2xl w-full max-h-[80vh] flex flex-col shadow-2xl"
+        onClick={(e) => e.stopPropagation()}
+      >
+        <div className="flex items-center justify-between px-5 py-4 border-b border-gray-200 dark:border-gray-800 flex-shrink-0">
+          <div>
+            <h3 id="plan-preview-title" className="font-semibold text-gray-900 dark:text-white">
+              {t("plan.title")}
+            </h3>
+            <p className="text-xs text-gray-700 dark:text-gray-400 mt-0.5">
+              {t("plan.subtitle", {
+                server: plan.server,
+                generated: new Date(plan.generated_at).toLocaleString(),
+              })}
+            </p>
+          </div>
+          <div className="flex items-center gap-3">
+            <button
+              type="button"
+              onClick={exportScript}
+              disabled={exporting}
+              className="inline-flex items-center gap-2 px-3 py-1.5 bg-gray-200 dark:bg-gray-800 hover:bg-gray-300 dark:hover:bg-gray-700 text-xs text-gray-700 dark:text-gray-300 rounded-lg transition-colors disabled:opacity-50"
+            >
+              {exporting ? <Loader2 size={14} className="animate-spin" /> : <Download size={14} />}
+              {t("plan.btnExport")}
+            </button>
+            <button
+              type="button"
+              onClick={onClose}
+              className="text-gray-400 hover:text-gray-700 dark:hover:text-white"
+              aria-label="Close"
+            >
+              <X size={18} />
+            </button>
+          </div>
+        </div>
+
+        {(exported || error) && (
+          <p
+            className={`px-5 py-2 text-xs border-b border-gray-200 dark:border-gray-800 break-all ${
+              error ? "text-red-600 dark:text-red-300" : "text-green-700 dark:text-green-300"
+            }`}
+          >
+            {error || t("plan.exported", { path: exported })}
+          </p>
+        )}
+
+        <div className="overflow-auto flex-1">
+          {plan.database_plans.map((db) => (
+            <DatabasePlanSection key={db.database_name} db={db} />
+          ))}
+        </div>
+      </div>
+    </div>
+  );
+}
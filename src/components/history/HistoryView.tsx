@@ -24,11 +24,21 @@ const COL_COUNT = 9; // 8 data columns + 1 expand chevron
 function dbStatusLabel(r: DatabaseResult, t: ReturnType<typeof useT>): { text: string; color: string } {
   if (r.critical_failure) return { text: t("history.statusFailed"), color: "text-red-500 dark:text-red-400" };
   if (r.interrupted) return { text: t("history.statusStopped"), color: "text-orange-600 dark:text-orange-400" };
-  if (r.manually_skipped) return { text: t("history.statusSkipped"), color: "text-amber-600 dark:text-amber-400" };
+  if (r.manually_skipped || r.skip_reason) return { text: t("history.statusSkipped"), color: "text-amber-600 dark:text-amber-400" };
   return { text: t("history.statusDone"), color: "text-green-600 dark:text-green-400" };
 }
 
 function indexStatusLabel(r: IndexResult, t: ReturnType<typeof useT>): { text: string; color: string } {
+  if (r.outcome === "deferred")
+    return { text: t("history.statusDeferred"), color: "text-sky-600 dark:text-sky-400" };
+  if (r.outcome === "pausedResumable")
+    return { text: t("history.statusPausedResumable"), color: "text-violet-600 dark:text-violet-400" };
+  if (r.outcome === "skippedByPolicy")
+    return { text: t("history.statusSkippedByPolicy"), color: "text-gray-500 dark:text-gray-400" };
+  if (r.outcome === "lowPriorityAborted")
+    return { text: t("history.statusLowPriorityAborted"), color: "text-amber-600 dark:text-amber-400" };
+  if (r.outcome === "blockingAborted")
+    return { text: t("history.statusBlockingAborted"), color: "text-amber-600 dark:text-amber-400" };
   if (!r.success) return { text: t("history.statusFailed"), color: "text-red-500 dark:text-red-400" };
   if (r.action === "SKIP") return { text: t("history.statusSkipped"), color: "text-gray-500 dark:text-gray-400" };
   return { text: t("history.statusDone"), color: "text-green-600 dark:text-green-400" };
@@ -65,6 +75,12 @@ function ExpandedDetail({ record, t }: { record: RunRecord; t: ReturnType<typeof
                     <span>{t("history.colRebuilt")}: <span className="text-blue-500">{db.indexes_rebuilt}</span></span>
                     <span>{t("history.colReorganized")}: <span className="text-purple-500">{db.indexes_reorganized}</span></span>
                     <span>{t("history.colSkipped")}: {db.indexes_skipped}</span>
+                    {(db.indexes_deferred ?? 0) > 0 && (
+                      <span>{t("history.colDeferred")}: <span className="text-sky-500">{db.indexes_deferred}</span></span>
+                    )}
+                    {(db.statistics_updated ?? 0) > 0 && (
+                      <span>{t("history.colStatistics")}: {db.statistics_updated}</span>
+                    )}
                     <span>{formatDuration(db.total_duration_secs)}</span>
                   </div>
                 </div>
@@ -96,12 +112,12 @@ function ExpandedDetail({ record, t }: { record: RunRecord; t: ReturnType<typeof
                         {db.index_results.map((idx) => {
                           const idxStatus = indexStatusLabel(idx, t);
                           return (
-                            <tr key={`${idx.schema_name}.${idx.table_name}.${idx.index_name}`} className="border-t border-gray-100 dark:border-gray-800/40">
-                              <td className="px-4 py-1.5 font-mono text-gray-700 dark:text-gray-300">{idx.index_name}</td>
+                            <tr key={`${idx.schema_name}.${idx.table_name}.${idx.index_name}.${idx.partition_number ?? ""}`} className="border-t border-gray-100 dark:border-gray-800/40">
+                              <td className="px-4 py-1.5 font-mono text-gray-700 dark:text-gray-300">{idx.index_name}{idx.partition_number != null && ` (p${idx.partition_number})`}</td>
                               <td className="px-4 py-1.5 text-gray-600 dark:text-gray-400">{idx.schema_name}.{idx.table_name}</td>
                               <td className="px-4 py-1.5 text-right text-gray-600 dark:text-gray-400">{idx.fragmentation_percent.toFixed(1)}%</td>
                               <td className="px-4 py-1.5 text-gray-600 dark:text-gray-400">{idx.action}</td>
-                              <td className={`px-4 py-1.5 font-medium ${idxStatus.color}`}>{idxStatus.text}</td>
+                              <td className={`px-4 py-1.5 font-medium ${idxStatus.color}`} title={idx.error ?? idx.reason}>{idxStatus.text}</td>
                               <td className="px-4 py-1.5 text-right text-gray-600 dark:text-gray-400">
                                 {idx.duration_secs > 0 ? formatDuration(idx.duration_secs) : "—"}
                               </td>
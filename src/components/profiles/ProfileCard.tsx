@@ -20,13 +20,18 @@ export function ProfileCard({ profile, onEdit }: Props) {
   const connectedProfileIds = useUiStore((s) => s.connectedProfileIds);
   const [testStatus, setTestStatus] = useState<TestStatus>("idle");
   const [testError, setTestError] = useState("");
+  const [testInfo, setTestInfo] = useState("");
   const alreadyOpened = connectedProfileIds.includes(profile.id);
 
   const handleTest = async () => {
     setTestStatus("testing");
     setTestError("");
+    setTestInfo("");
     try {
-      await api.testConnection(profile.id);
+      const result = await api.testConnection(profile.id);
+      setTestInfo(
+        `${result.auth_scheme ?? t("profiles.authSchemeUnknown")} · ${result.login_name}`
+      );
       setTestStatus("success");
     } catch (e) {
       setTestError(String(e));
@@ -47,7 +52,10 @@ export function ProfileCard({ profile, onEdit }: Props) {
 
         <div className="flex items-center gap-2 ml-4 flex-shrink-0">
           {testStatus === "success" && (
-            <span className="flex items-center gap-1 text-xs text-green-600 dark:text-green-400">
+            <span
+              className="flex items-center gap-1 text-xs text-green-600 dark:text-green-400"
+              title={testInfo}
+            >
               <CheckCircle size={12} /> {t("profileCard.connected")}
             </span>
           )}
@@ -3,7 +3,7 @@ import { useRef, useState } from "react";
 import { useT } from "../../i18n";
 import { useDialogA11y } from "../../hooks/useDialogA11y";
 import { useProfileStore } from "../../store/profileStore";
-import type { ServerProfile } from "../../types";
+import type { AuthType, ServerProfile } from "../../types";
 
 interface Props {
   profile?: ServerProfile;
@@ -13,13 +13,18 @@ interface Props {
 const DEFAULTS: Omit<ServerProfile, "id" | "name"> = {
   server: "",
   port: 1433,
+  instance_name: "",
   auth_type: "sqlServer",
   username: "",
   password: "",
   encrypt: true,
   trust_server_certificate: true,
+  tenant_id: "",
 };
 
+// The driver only implements NTLM on Windows; elsewhere the option would always fail.
+const IS_WINDOWS = navigator.userAgent.includes("Windows");
+
 export function ProfileFormModal({ profile, onClose }: Props) {
   const t = useT();
   const { save } = useProfileStore();
@@ -40,7 +45,13 @@ export function ProfileFormModal({ profile, onClose }: Props) {
 
   const handleSubmit = async (e: React.FormEvent) => {
     e.preventDefault();
-    if (!form.name || !form.server || !form.username) {
+    // A pre-supplied access token carries the identity, so no username is needed.
+    const needsUsername = form.auth_type !== "azureAdToken";
+    if (!form.name || !form.server || (needsUsername && !form.username)) {
+      setError(t("profileForm.validationError"));
+      return;
+    }
+    if (form.auth_type === "azureAdServicePrincipal" && !form.tenant_id) {
       setError(t("profileForm.validationError"));
       return;
     }
@@ -59,6 +70,19 @@ export function ProfileFormModal({ profile, onClose }: Props) {
     }
   };
 
+  const usernameLabel =
+    form.auth_type === "windowsCredentials"
+      ? `${t("profileForm.usernameLabel")} ${t("profileForm.windowsDomainHint")}`
+      : form.auth_type === "azureAdServicePrincipal"
+        ? t("profileForm.clientIdLabel")
+        : t("profileForm.usernameLabel");
+  const passwordLabel =
+    form.auth_type === "azureAdServicePrincipal"
+      ? t("profileForm.clientSecretLabel")
+      : form.auth_type === "azureAdToken"
+        ? t("profileForm.accessTokenLabel")
+        : t("profileForm.passwordLabel");
+
   const INPUT_CLS =
     "w-full bg-white dark:bg-gray-800 border border-gray-300 dark:border-gray-700 rounded-lg px-3 py-2 text-sm text-gray-900 dark:text-white placeholder-gray-400 dark:placeholder-gray-500 focus:outline-none focus:border-blue-500 transition-colors";
 
@@ -95,7 +119,7 @@ export function ProfileFormModal({ profile, onClose }: Props) {
             />
           </Field>
 
-          <div className="grid grid-cols-3 gap-3">
+          <div className="grid grid-cols-4 gap-3">
             <div className="col-span-2">
               <Field label={t("profileForm.serverLabel")}>
                 <input
@@ -106,25 +130,70 @@ export function ProfileFormModal({ profile, onClose }: Props) {
                 />
               </Field>
             </div>
+            <Field label={t("profileForm.instanceLabel")}>
+              <input
+                value={form.instance_name}
+                onChange={(e) => set("instance_name", e.target.value)}
+                title={t("profileForm.instanceHelp")}
+                className={INPUT_CLS}
+              />
+            </Field>
             <Field label={t("profileForm.portLabel")}>
               <input
                 type="number"
                 value={form.port}
                 onChange={(e) => set("port", Number(e.target.value))}
-                className={INPUT_CLS}
+                disabled={form.instance_name.trim() !== ""}
+                className={`${INPUT_CLS} disabled:opacity-50`}
               />
             </Field>
           </div>
 
-          <div className="grid grid-cols-2 gap-3">
-            <Field label={t("profileForm.usernameLabel")}>
+          <Field label={t("profileForm.authTypeLabel")}>
+            <select
+              value={form.auth_type}
+              onChange={(e) => set("auth_type", e.target.value as AuthType)}
+              className={INPUT_CLS}
+            >
+              <option value="sqlServer">{t("profileForm.authSqlServer")}</option>
+              {(IS_WINDOWS || form.auth_type === "windowsCredentials") && (
+                <option value="windowsCredentials" disabled={!IS_WINDOWS}>
+                  {t("profileForm.authWindowsCredentials")}
+                </option>
+              )}
+              <option value="azureAdServicePrincipal">
+                {t("profileForm.authAzureAdServicePrincipal")}
+              </option>
+              <option value="azureAdToken">{t("profileForm.authAzureAdToken")}</option>
+            </select>
+            {form.auth_type === "windowsCredentials" && (
+              <p className="mt-1 text-xs text-gray-600 dark:text-gray-500">
+                {t("profileForm.authWindowsNote")}
+              </p>
+            )}
+          </Field>
+
+          {form.auth_type === "azureAdServicePrincipal" && (
+            <Field label={t("profileForm.tenantIdLabel")}>
               <input
-                value={form.username}
-                onChange={(e) => set("username", e.target.value)}
+                value={form.tenant_id}
+                onChange={(e) => set("tenant_id", e.target.value)}
                 className={INPUT_CLS}
               />
             </Field>
-            <Field label={t("profileForm.passwordLabel")}>
+          )}
+
+          <div className="grid grid-cols-2 gap-3">
+            {form.auth_type !== "azureAdToken" && (
+              <Field label={usernameLabel}>
+                <input
+                  value={form.username}
+                  onChange={(e) => set("username", e.target.value)}
+                  className={INPUT_CLS}
+                />
+              </Field>
+            )}
+            <Field label={passwordLabel}>
               <input
                 type="password"
                 value={form.password}
@@ -15,7 +15,7 @@ function DbRow({ r, t }: { r: DatabaseResult; t: ReturnType<typeof useT> }) {
     ? "text-red-500 dark:text-red-400"
     : r.interrupted
     ? "text-orange-600 dark:text-orange-400"
-    : r.manually_skipped
+    : r.manually_skipped || r.skip_reason
     ? "text-amber-600 dark:text-amber-400"
     : "text-green-600 dark:text-green-400";
 
@@ -23,14 +23,16 @@ function DbRow({ r, t }: { r: DatabaseResult; t: ReturnType<typeof useT> }) {
     ? t("summary.statusFailed")
     : r.interrupted
     ? t("summary.statusStopped")
-    : r.manually_skipped
+    : r.manually_skipped || r.skip_reason
     ? t("summary.statusSkipped")
     : t("summary.statusDone");
 
   return (
     <tr className="border-t border-gray-100 dark:border-gray-800 hover:bg-gray-50 dark:hover:bg-gray-800/40">
       <td className="px-4 py-3 text-sm font-mono text-gray-700 dark:text-gray-200">{r.database_name}</td>
-      <td className={`px-4 py-3 text-sm font-medium ${statusColor}`}>{statusText}</td>
+      <td className={`px-4 py-3 text-sm font-medium ${statusColor}`} title={r.skip_reason}>
+        {statusText}
+      </td>
       <td className="px-4 py-3 text-sm text-blue-500 dark:text-blue-400 text-right">{r.indexes_rebuilt}</td>
       <td className="px-4 py-3 text-sm text-purple-500 dark:text-purple-400 text-right">
         {r.indexes_reorganized}
@@ -96,6 +98,11 @@ export function ResultsSummary() {
             <p className="text-sm text-gray-600 dark:text-gray-500 mt-0.5">
               {t("summary.completedIn", { duration: fmt(run.summary.total_duration_secs) })}
             </p>
+            {run.summary.total_indexes_deferred > 0 && (
+              <p className="text-sm text-sky-600 dark:text-sky-400 mt-0.5">
+                {t("summary.deferredByWindow", { count: run.summary.total_indexes_deferred })}
+              </p>
+            )}
           </div>
           <button
             onClick={handleRunAgain}
@@ -1,11 +1,14 @@
 import { listen } from "@tauri-apps/api/event";
 import { useEffect } from "react";
 import type {
+  AgThrottlePayload,
   DbCompletePayload,
   DbStartPayload,
   IndexActionPayload,
   IndexCompletePayload,
   IndexFoundPayload,
+  IndexProgressPayload,
+  LogUsagePayload,
   MaintenanceControlPayload,
   MaintenanceErrorPayload,
   MaintenanceFinishedPayload,
@@ -34,6 +37,9 @@ export function useMaintenanceEvents() {
         listen<IndexActionPayload>("maintenance:index-action", (e) =>
           store().handleIndexAction(e.payload)
         ),
+        listen<IndexProgressPayload>("maintenance:index-progress", (e) =>
+          store().handleIndexProgress(e.payload)
+        ),
         listen<IndexCompletePayload>("maintenance:index-complete", (e) =>
           store().handleIndexComplete(e.payload)
         ),
@@ -60,6 +66,12 @@ export function useMaintenanceEvents() {
             store().handleStopSignal(e.payload.profile_id);
           }
         }),
+        listen<LogUsagePayload>("maintenance:log-usage", (e) =>
+          store().handleLogUsage(e.payload)
+        ),
+        listen<AgThrottlePayload>("maintenance:ag-throttle", (e) =>
+          store().handleAgThrottle(e.payload)
+        ),
         listen<MaintenanceErrorPayload>("maintenance:error", (e) =>
           console.error(
             `[Maintenance:${e.payload.profile_id}]`,
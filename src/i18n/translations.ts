@@ -23,6 +23,7 @@ const en: Translations = {
   "profiles.subtitle": "Manage SQL Server connection profiles",
   "profiles.newProfile": "New Profile",
   "profiles.empty": "No profiles yet. Create one to get started.",
+  "profiles.authSchemeUnknown": "auth scheme unknown",
 
   // Profile card
   "profileCard.connected": "Connected",
@@ -42,6 +43,8 @@ const en: Translations = {
   "profileForm.serverLabel": "Server *",
   "profileForm.serverPlaceholder": "myserver.database.windows.net",
   "profileForm.portLabel": "Port",
+  "profileForm.instanceLabel": "Instance",
+  "profileForm.instanceHelp": "Named instance — the port is looked up via SQL Server Browser.",
   "profileForm.usernameLabel": "Username *",
   "profileForm.passwordLabel": "Password",
   "profileForm.passwordHelpEdit": "Leave blank to keep the current password.",
@@ -61,6 +64,10 @@ const en: Translations = {
   "databases.filterClear": "Clear filter",
   "databases.noDataEmpty": "No databases loaded yet.",
   "databases.noDataHint": "Use \"Refresh Databases\" to fetch all user databases from this server.",
+  "databases.agPrimary": "AG primary",
+  "databases.agSecondary": "AG secondary — will be skipped",
+  "databases.agUnknown": "AG role unknown",
+  "databases.agUnknownHint": "Availability Group roles could not be read; they need VIEW SERVER STATE",
   "databases.loading": "Loading databases...",
   "databases.selectAll": "Select All",
   "databases.deselectAll": "Deselect All",
@@ -76,6 +83,8 @@ const en: Translations = {
   "databases.btnLoadFirst": "Load databases to continue",
   "databases.btnSelectOne": "Select at least one database",
   "databases.btnStart": "Start Maintenance ({count} databases)",
+  "databases.btnPreviewPlan": "Preview plan",
+  "databases.btnPlanning": "Analysing...",
 
   // Status bar
   "databases.statusSelected": "{selected} selected / {total} total databases",
@@ -85,15 +94,50 @@ const en: Translations = {
   "options.sqlTimeouts": "SQL Timeouts",
   "options.connectionTimeout": "Connection timeout (ms)",
   "options.requestTimeout": "Request timeout (ms)",
+  "options.session": "Session Settings",
+  "options.applicationName": "Application name",
+  "options.applicationNameHint": "How monitoring identifies maintenance sessions",
+  "options.lockTimeout": "Lock timeout (ms)",
+  "options.lockTimeoutHint": "0 = wait indefinitely; timed-out operations are retried",
+  "options.deadlockPriorityLow": "Low deadlock priority",
+  "options.deadlockPriorityLowDesc": "Deadlocks always pick maintenance as the victim",
   "options.noTimeout": "0 = no timeout",
   "options.rebuildOnline": "REBUILD with ONLINE = ON",
   "options.rebuildOnlineDesc": "Allows concurrent reads/writes during rebuild",
+  "options.onlineFallback": "When online rebuild is unavailable",
+  "options.onlineFallbackDesc": "Standard edition, text/image columns, older versions",
+  "options.onlineFallbackOffline": "Rebuild offline",
+  "options.onlineFallbackSkip": "Skip index",
+  "options.resumableRebuild": "Resumable rebuilds",
+  "options.resumableRebuildDesc": "Pause or stop pauses the rebuild on the server; the next run resumes it (SQL Server 2017+)",
+  "options.resumableMaxDuration": "Max rebuild duration (minutes)",
+  "options.lowPriorityWait": "Wait at low priority",
+  "options.lowPriorityWaitDesc": "Queue for the final schema lock behind production queries instead of blocking them",
+  "options.lowPriorityMaxDuration": "Low priority wait (minutes)",
+  "options.abortAfterWait": "After waiting",
+  "options.rebuildSettings": "Rebuild Settings",
+  "options.maxdop": "MAXDOP",
+  "options.serverDefault": "0 = server default",
+  "options.fillFactor": "Fill factor (%)",
+  "options.fillFactorHint": "0 = keep each index's fill factor",
+  "options.dataCompression": "Data compression",
+  "options.keepExisting": "Keep",
+  "options.padIndex": "PAD_INDEX",
+  "options.padIndexDesc": "Apply the fill factor to intermediate pages too",
+  "options.sortInTempdb": "SORT_IN_TEMPDB",
+  "options.sortInTempdbDesc": "Sort in tempdb; ignored by resumable rebuilds",
   "options.freeProcCache": "DBCC FREEPROCCACHE after run",
   "options.freeProcCacheDesc": "Force execution plan recompilation after maintenance",
   "options.retry": "Retry",
   "options.maxAttempts": "Max attempts",
   "options.baseDelay": "Base delay (ms)",
   "options.maxDelay": "Max delay (ms)",
+  "options.retryableErrors": "Retryable SQL error numbers",
+  "options.retryableErrorsHint": "Deadlocks, lock timeouts and Azure SQL throttling by default; timeouts and dropped connections are set below",
+  "options.retryTimeouts": "Retry timeouts",
+  "options.retryTimeoutsDesc": "Cancel a request that hit the request timeout and retry it on a new connection; resumable rebuilds are paused instead",
+  "options.retryConnectionErrors": "Retry dropped connections",
+  "options.retryConnectionErrorsDesc": "Reconnect and retry when the connection fails during a request",
 
   // Maintenance dashboard
   "dashboard.title": "Maintenance Dashboard",
@@ -105,6 +149,9 @@ const en: Translations = {
   "dashboard.skipped": "Skipped",
   "dashboard.failedDbs": "Failed DBs",
   "dashboard.viewSummary": "View Summary",
+  "dashboard.logUsageWaiting": "{db}: transaction log {used}% full (limit {max}%), waiting for log space — log reuse wait: {reuseWait}",
+  "dashboard.logUsagePaused": "{db}: transaction log {used}% full (limit {max}%), run paused — free log space, then resume. Log reuse wait: {reuseWait}",
+  "dashboard.agThrottle": "{db}: waiting for Availability Group replicas to catch up — log send queue {send} MB, redo queue {redo} MB",
 
   // Run state labels
   "runState.idle": "idle",
@@ -147,6 +194,7 @@ const en: Translations = {
   "summary.noProfile": "Select a connected profile tab to view its summary.",
   "summary.noSummary": "No summary yet for this profile. Complete a run first.",
   "summary.completedIn": "Completed in {duration}",
+  "summary.deferredByWindow": "{count} index operation(s) deferred — the maintenance window closed",
   "summary.runAgain": "Run Again",
   "summary.statDatabases": "Databases",
   "summary.statRebuilt": "Indexes Rebuilt",
@@ -179,6 +227,17 @@ const en: Translations = {
   "drawer.colDuration": "Duration",
   "drawer.colRetries": "Retries",
   "drawer.colError": "Error",
+  "drawer.progressPercent": "{percent}% done, ~{remaining} left",
+  "drawer.progressWaiting": "waiting on {wait} for {secs}",
+  "drawer.progressWork": "CPU {cpu}, {reads} logical reads, {writes} writes",
+  "plan.title": "Maintenance plan",
+  "plan.subtitle": "{server} · analysed {generated} · nothing was changed",
+  "plan.dbSummary": "{operations} operations, {deferred} deferred, {skipped} skipped, {statistics} statistics",
+  "plan.deferred": "DEFERRED",
+  "plan.colDetail": "Reason / T-SQL",
+  "plan.statistics": "Statistics updates ({count})",
+  "plan.btnExport": "Export T-SQL script",
+  "plan.exported": "Script saved to {path}",
 
   // About modal
   "about.madeBy": "Made by",
@@ -195,14 +254,114 @@ const en: Translations = {
   "profileForm.authWindowsCredentials": "Windows (Credentials)",
   "profileForm.authWindowsNote": "Windows authentication requires running on Windows",
   "profileForm.windowsDomainHint": "(DOMAIN\\user)",
+  "profileForm.authAzureAdServicePrincipal": "Azure AD (Service Principal)",
+  "profileForm.authAzureAdToken": "Azure AD (Access Token)",
+  "profileForm.tenantIdLabel": "Tenant ID *",
+  "profileForm.clientIdLabel": "Client ID *",
+  "profileForm.clientSecretLabel": "Client Secret",
+  "profileForm.accessTokenLabel": "Access Token",
 
   // Fragmentation thresholds (options panel)
   "options.thresholds": "Fragmentation Thresholds",
   "options.reorganizeThreshold": "Reorganize threshold (%)",
   "options.rebuildThreshold": "Rebuild threshold (%)",
   "options.thresholdHint": "Rebuild threshold should be ≥ reorganize threshold",
+  "options.reorganizeOnly": "Reorganize only",
+  "options.reorganizeOnlyDesc": "Never rebuild; indexes past the rebuild threshold are reorganized and heaps skipped",
+  "options.scanMode": "Physical stats scan mode",
+  "options.scanModeDesc": "SAMPLED and DETAILED report page density but read more pages",
+  "options.pageDensityThreshold": "Rebuild below page density (%)",
+  "options.pageDensityThresholdHint": "0 = disabled; requires SAMPLED or DETAILED",
+  "options.minPageCount": "Minimum page count",
+  "options.maxPageCount": "Maximum page count",
+  "options.noLimit": "0 = no limit",
+
+  // Columnstore thresholds (options panel)
+  "options.columnstore": "Columnstore Thresholds",
+  "options.columnstoreReorganizeThreshold": "Reorganize at deleted rows (%)",
+  "options.columnstoreRebuildThreshold": "Rebuild at deleted rows (%)",
+  "options.columnstoreDeltaRowgroupThreshold": "Reorganize at delta rowgroups",
+  "options.columnstoreDeltaRowgroupHint": "Open or closed rowgroups not yet compressed; 0 = disabled",
+
+  // Heaps (options panel)
+  "options.heaps": "Heaps",
+  "options.includeHeaps": "Include heaps",
+  "options.includeHeapsDesc": "Rebuild tables without a clustered index with ALTER TABLE ... REBUILD",
+  "options.heapForwardedRecordThreshold": "Rebuild at forwarded records (%)",
+  "options.heapForwardedRecordHint": "0 = disabled; requires SAMPLED or DETAILED",
+  "options.heapFragmentationThreshold": "Rebuild at fragmentation (%)",
+  "options.disabledWhenZero": "0 = disabled",
+
+  // Statistics (options panel)
+  "options.statistics": "Statistics",
+  "options.updateStatistics": "Update statistics",
+  "options.updateStatisticsDesc": "Update stale statistics after index maintenance; statistics refreshed by a rebuild are left alone",
+  "options.includeColumnStatistics": "Include column statistics",
+  "options.includeColumnStatisticsDesc": "Also update auto-created and user-created column statistics",
+  "options.statisticsModificationThreshold": "Update at modified rows (%)",
+  "options.statisticsMaxAgeDays": "Update when older than (days)",
+  "options.statisticsSampling": "Sampling",
+  "options.statisticsSamplingDefault": "Default",
+  "options.statisticsSamplingSample": "Percent",
+  "options.statisticsSamplePercent": "Sample (%)",
+
+  // Object filters (options panel)
+  "options.objectFilters": "Object Filters",
+  "options.objectFiltersHint": "One LIKE pattern per line (% and _ wildcards). Excluded objects are never touched.",
+  "options.databaseFilter": "Databases",
+  "options.schemaFilter": "Schemas",
+  "options.tableFilter": "Tables",
+  "options.tableFilterHint": "Patterns with a dot match schema.table",
+  "options.indexFilter": "Indexes",
+  "options.filterInclude": "Include (empty = all)",
+  "options.filterExclude": "Exclude",
+
+  // Overrides (options panel)
+  "options.overrides": "Per-Database Overrides",
+  "options.overridesHint": "Replace the settings above for matching databases, or for matching tables when a table pattern is set. Empty fields inherit; later entries win.",
+  "options.overrideDatabase": "Database pattern",
+  "options.overrideTable": "Table pattern (optional)",
+  "options.overrideInherit": "Inherit",
+  "options.overrideOn": "On",
+  "options.overrideOff": "Off",
+  "options.overrideReorganizeThreshold": "Reorganize %",
+  "options.overrideRebuildThreshold": "Rebuild %",
+  "options.overrideMaxdop": "MAXDOP",
+  "options.overrideFillFactor": "Fill factor",
+  "options.overrideOnline": "Online",
+  "options.overrideReorganizeOnly": "Reorganize only",
+  "options.overrideCompression": "Compression",
+  "options.overrideAdd": "Add override",
+  "options.overrideRemove": "Remove override",
+
+  // Maintenance window (options panel)
+  "options.maintenanceWindow": "Maintenance Window",
+  "options.windowEndTime": "Window ends at",
+  "options.windowEndTimeHint": "Local time; empty = no end time",
+  "options.maxRunMinutes": "Max run duration (minutes)",
+  "options.indexPriority": "Processing order",
+  "options.indexPriorityHint": "Which indexes go first when the window may not fit them all",
+  "options.priorityFragmentation": "Most fragmented",
+  "options.priorityLargestBenefit": "Largest benefit",
+  "options.priorityMostUsed": "Most used",
+  "options.prioritySmallestFirst": "Smallest first",
+  "options.priorityLeastRecentlyMaintained": "Least recently maintained",
 
   // Parallel processing (options panel)
+  "options.logUsage": "Transaction Log Guard",
+  "options.logUsageMaxPercent": "Hold operations at log usage (%)",
+  "options.logUsageAction": "When the log is too full",
+  "options.logUsageActionHint": "Checked before each index operation",
+  "options.logUsageWait": "Wait for log backup",
+  "options.logUsagePause": "Pause the run",
+  "options.availabilityGroups": "Availability Groups",
+  "options.availabilityGroupsHint": "Databases on a secondary replica are always skipped. Operations on a primary wait while any secondary's queue is above the limit.",
+  "options.agMaxLogSendQueue": "Max log send queue (MB)",
+  "options.agMaxRedoQueue": "Max redo queue (MB)",
+  "options.blockingMonitor": "Blocking Monitor",
+  "options.blockingMonitorHint": "Cancels an index operation that blocks other sessions, or is blocked, for too long. Resumable rebuilds are paused; other operations are killed and roll back. Needs VIEW SERVER STATE and, to kill, ALTER ANY CONNECTION.",
+  "options.maxBlockingSecs": "Cancel when blocking others for (s)",
+  "options.maxBlockedSecs": "Cancel when blocked for (s)",
   "options.parallel": "Parallel Processing",
   "options.parallelDatabases": "Process databases in parallel",
   "options.parallelDatabasesDesc": "Run multiple databases concurrently",
@@ -232,6 +391,11 @@ const en: Translations = {
   "history.colRebuilt": "Rebuilt",
   "history.colReorganized": "Reorganized",
   "history.colSkipped": "Skipped",
+  "history.colStatistics": "Statistics",
+  "history.colDeferred": "Deferred",
+  "history.statusDeferred": "Deferred",
+  "history.statusPausedResumable": "Paused (resumes next run)",
+  "history.statusSkippedByPolicy": "Excluded",
   "history.confirmClear": "Confirm Clear",
   "history.cancel": "Cancel",
   "history.noDetails": "No details available for this run.",
@@ -239,6 +403,8 @@ const en: Translations = {
   "history.statusStopped": "Stopped",
   "history.statusFailed": "Failed",
   "history.statusSkipped": "Skipped",
+  "history.statusLowPriorityAborted": "Yielded (low priority)",
+  "history.statusBlockingAborted": "Aborted: blocking",
   "history.colIndex": "Index",
   "history.colTable": "Table",
   "history.colFrag": "Frag%",
@@ -266,6 +432,7 @@ const esAR: Translations = {
   "profiles.subtitle": "Administrá tus perfiles de conexión SQL Server",
   "profiles.newProfile": "Nuevo Perfil",
   "profiles.empty": "Sin perfiles aún. Creá uno para comenzar.",
+  "profiles.authSchemeUnknown": "esquema de autenticación desconocido",
 
   // Profile card
   "profileCard.connected": "Conectado",
@@ -285,6 +452,8 @@ const esAR: Translations = {
   "profileForm.serverLabel": "Servidor *",
   "profileForm.serverPlaceholder": "miservidor.database.windows.net",
   "profileForm.portLabel": "Puerto",
+  "profileForm.instanceLabel": "Instancia",
+  "profileForm.instanceHelp": "Instancia con nombre — el puerto se obtiene mediante SQL Server Browser.",
   "profileForm.usernameLabel": "Usuario *",
   "profileForm.passwordLabel": "Contraseña",
   "profileForm.passwordHelpEdit": "Dejá en blanco para mantener la contraseña actual.",
@@ -304,6 +473,10 @@ const esAR: Translations = {
   "databases.filterClear": "Limpiar filtro",
   "databases.noDataEmpty": "Sin bases de datos cargadas aún.",
   "databases.noDataHint": "Usá \"Actualizar Bases de Datos\" para obtener todas las bases de datos del servidor.",
+  "databases.agPrimary": "AG primaria",
+  "databases.agSecondary": "AG secundaria — se omitirá",
+  "databases.agUnknown": "Rol de AG desconocido",
+  "databases.agUnknownHint": "No se pudieron leer los roles de Availability Group; requieren VIEW SERVER STATE",
   "databases.loading": "Cargando bases de datos...",
   "databases.selectAll": "Seleccionar Todo",
   "databases.deselectAll": "Deseleccionar Todo",
@@ -319,6 +492,8 @@ const esAR: Translations = {
   "databases.btnLoadFirst": "Cargá las bases de datos para continuar",
   "databases.btnSelectOne": "Seleccioná al menos una base de datos",
   "databases.btnStart": "Iniciar Mantenimiento ({count} bases de datos)",
+  "databases.btnPreviewPlan": "Ver plan",
+  "databases.btnPlanning": "Analizando...",
 
   // Status bar
   "databases.statusSelected": "{selected} seleccionadas / {total} bases de datos totales",
@@ -328,15 +503,50 @@ const esAR: Translations = {
   "options.sqlTimeouts": "Tiempos de Espera SQL",
   "options.connectionTimeout": "Tiempo de conexión (ms)",
   "options.requestTimeout": "Tiempo de solicitud (ms)",
+  "options.session": "Configuración de Sesión",
+  "options.applicationName": "Nombre de aplicación",
+  "options.applicationNameHint": "Cómo el monitoreo identifica las sesiones de mantenimiento",
+  "options.lockTimeout": "Timeout de bloqueo (ms)",
+  "options.lockTimeoutHint": "0 = esperar indefinidamente; las operaciones que expiran se reintentan",
+  "options.deadlockPriorityLow": "Prioridad de deadlock baja",
+  "options.deadlockPriorityLowDesc": "Los deadlocks siempre eligen al mantenimiento como víctima",
   "options.noTimeout": "0 = sin límite",
   "options.rebuildOnline": "REBUILD con ONLINE = ON",
   "options.rebuildOnlineDesc": "Permite lecturas/escrituras simultáneas durante el rebuild",
+  "options.onlineFallback": "Cuando no se puede reconstruir en línea",
+  "options.onlineFallbackDesc": "Edición Standard, columnas text/image, versiones antiguas",
+  "options.onlineFallbackOffline": "Reconstruir sin conexión",
+  "options.onlineFallbackSkip": "Omitir índice",
+  "options.resumableRebuild": "Reconstrucciones reanudables",
+  "options.resumableRebuildDesc": "Pausar o detener pausa la reconstrucción en el servidor; la próxima ejecución la reanuda (SQL Server 2017+)",
+  "options.resumableMaxDuration": "Duración máxima de reconstrucción (minutos)",
+  "options.lowPriorityWait": "Esperar con baja prioridad",
+  "options.lowPriorityWaitDesc": "Esperar el bloqueo de esquema final detrás de las consultas de producción en lugar de bloquearlas",
+  "options.lowPriorityMaxDuration": "Espera de baja prioridad (minutos)",
+  "options.abortAfterWait": "Tras la espera",
+  "options.rebuildSettings": "Opciones de Reconstrucción",
+  "options.maxdop": "MAXDOP",
+  "options.serverDefault": "0 = valor del servidor",
+  "options.fillFactor": "Factor de relleno (%)",
+  "options.fillFactorHint": "0 = conservar el factor de relleno de cada índice",
+  "options.dataCompression": "Compresión de datos",
+  "options.keepExisting": "Conservar",
+  "options.padIndex": "PAD_INDEX",
+  "options.padIndexDesc": "Aplicar el factor de relleno también a las páginas intermedias",
+  "options.sortInTempdb": "SORT_IN_TEMPDB",
+  "options.sortInTempdbDesc": "Ordenar en tempdb; se ignora en reconstrucciones reanudables",
   "options.freeProcCache": "DBCC FREEPROCCACHE al finalizar",
   "options.freeProcCacheDesc": "Fuerza la recompilación de planes de ejecución tras el mantenimiento",
   "options.retry": "Reintentos",
   "options.maxAttempts": "Intentos máximos",
   "options.baseDelay": "Demora base (ms)",
   "options.maxDelay": "Demora máxima (ms)",
+  "options.retryableErrors": "Números de error SQL reintentables",
+  "options.retryableErrorsHint": "Por defecto, deadlocks, timeouts de bloqueo y limitación de Azure SQL; los timeouts y las conexiones caídas se configuran abajo",
+  "options.retryTimeouts": "Reintentar timeouts",
+  "options.retryTimeoutsDesc": "Cancelar la solicitud que superó el timeout y reintentarla en una conexión nueva; las reconstrucciones reanudables se pausan",
+  "options.retryConnectionErrors": "Reintentar conexiones caídas",
+  "options.retryConnectionErrorsDesc": "Reconectar y reintentar cuando la conexión falla durante una solicitud",
 
   // Maintenance dashboard
   "dashboard.title": "Panel de Mantenimiento",
@@ -348,6 +558,9 @@ const esAR: Translations = {
   "dashboard.skipped": "Omitidos",
   "dashboard.failedDbs": "BDs con Error",
   "dashboard.viewSummary": "Ver Resumen",
+  "dashboard.logUsageWaiting": "{db}: log de transacciones al {used}% (límite {max}%), esperando espacio de log — log reuse wait: {reuseWait}",
+  "dashboard.logUsagePaused": "{db}: log de transacciones al {used}% (límite {max}%), ejecución pausada — liberá espacio de log y reanudá. Log reuse wait: {reuseWait}",
+  "dashboard.agThrottle": "{db}: esperando que las réplicas del Availability Group se pongan al día — cola de envío de log {send} MB, cola de redo {redo} MB",
 
   // Run state labels
   "runState.idle": "inactivo",
@@ -390,6 +603,7 @@ const esAR: Translations = {
   "summary.noProfile": "Seleccioná una pestaña de perfil conectado para ver su resumen.",
   "summary.noSummary": "Sin resumen para este perfil. Completá una ejecución primero.",
   "summary.completedIn": "Completado en {duration}",
+  "summary.deferredByWindow": "{count} operación(es) de índice aplazada(s): la ventana de mantenimiento se cerró",
   "summary.runAgain": "Ejecutar de Nuevo",
   "summary.statDatabases": "Bases de Datos",
   "summary.statRebuilt": "Índices Reconstruidos",
@@ -422,6 +636,17 @@ const esAR: Translations = {
   "drawer.colDuration": "Duración",
   "drawer.colRetries": "Reintentos",
   "drawer.colError": "Error",
+  "drawer.progressPercent": "{percent}% completado, ~{remaining} restantes",
+  "drawer.progressWaiting": "esperando {wait} hace {secs}",
+  "drawer.progressWork": "CPU {cpu}, {reads} lecturas lógicas, {writes} escrituras",
+  "plan.title": "Plan de mantenimiento",
+  "plan.subtitle": "{server} · analizado {generated} · no se modificó nada",
+  "plan.dbSummary": "{operations} operaciones, {deferred} diferidas, {skipped} omitidas, {statistics} estadísticas",
+  "plan.deferred": "DIFERIDA",
+  "plan.colDetail": "Motivo / T-SQL",
+  "plan.statistics": "Actualizaciones de estadísticas ({count})",
+  "plan.btnExport": "Exportar script T-SQL",
+  "plan.exported": "Script guardado en {path}",
 
   // About modal
   "about.madeBy": "Hecho por",
@@ -438,14 +663,114 @@ const esAR: Translations = {
   "profileForm.authWindowsCredentials": "Windows (Credenciales)",
   "profileForm.authWindowsNote": "La autenticación de Windows requiere ejecutar en Windows",
   "profileForm.windowsDomainHint": "(DOMINIO\\usuario)",
+  "profileForm.authAzureAdServicePrincipal": "Azure AD (Entidad de Servicio)",
+  "profileForm.authAzureAdToken": "Azure AD (Token de Acceso)",
+  "profileForm.tenantIdLabel": "ID de Inquilino *",
+  "profileForm.clientIdLabel": "ID de Cliente *",
+  "profileForm.clientSecretLabel": "Secreto de Cliente",
+  "profileForm.accessTokenLabel": "Token de Acceso",
 
   // Fragmentation thresholds (options panel)
   "options.thresholds": "Umbrales de Fragmentación",
   "options.reorganizeThreshold": "Umbral de reorganización (%)",
   "options.rebuildThreshold": "Umbral de reconstrucción (%)",
   "options.thresholdHint": "El umbral de reconstrucción debe ser ≥ al de reorganización",
+  "options.reorganizeOnly": "Solo reorganizar",
+  "options.reorganizeOnlyDesc": "Nunca reconstruir; los índices sobre el umbral de reconstrucción se reorganizan y los heaps se omiten",
+  "options.scanMode": "Modo de escaneo de estadísticas físicas",
+  "options.scanModeDesc": "SAMPLED y DETAILED informan la densidad de página pero leen más páginas",
+  "options.pageDensityThreshold": "Reconstruir bajo densidad de página (%)",
+  "options.pageDensityThresholdHint": "0 = desactivado; requiere SAMPLED o DETAILED",
+  "options.minPageCount": "Cantidad mínima de páginas",
+  "options.maxPageCount": "Cantidad máxima de páginas",
+  "options.noLimit": "0 = sin límite",
+
+  // Columnstore thresholds (options panel)
+  "options.columnstore": "Umbrales de Columnstore",
+  "options.columnstoreReorganizeThreshold": "Reorganizar con filas eliminadas (%)",
+  "options.columnstoreRebuildThreshold": "Reconstruir con filas eliminadas (%)",
+  "options.columnstoreDeltaRowgroupThreshold": "Reorganizar con rowgroups delta",
+  "options.columnstoreDeltaRowgroupHint": "Rowgroups abiertos o cerrados aún sin comprimir; 0 = desactivado",
+
+  // Heaps (options panel)
+  "options.heaps": "Heaps",
+  "options.includeHeaps": "Incluir heaps",
+  "options.includeHeapsDesc": "Reconstruir tablas sin índice agrupado con ALTER TABLE ... REBUILD",
+  "options.heapForwardedRecordThreshold": "Reconstruir con registros reenviados (%)",
+  "options.heapForwardedRecordHint": "0 = desactivado; requiere SAMPLED o DETAILED",
+  "options.heapFragmentationThreshold": "Reconstruir con fragmentación (%)",
+  "options.disabledWhenZero": "0 = desactivado",
+
+  // Statistics (options panel)
+  "options.statistics": "Estadísticas",
+  "options.updateStatistics": "Actualizar estadísticas",
+  "options.updateStatisticsDesc": "Actualizar estadísticas desactualizadas tras el mantenimiento de índices; las que ya refrescó una reconstrucción se omiten",
+  "options.includeColumnStatistics": "Incluir estadísticas de columna",
+  "options.includeColumnStatisticsDesc": "Actualizar también las estadísticas de columna creadas automáticamente o por el usuario",
+  "options.statisticsModificationThreshold": "Actualizar con filas modificadas (%)",
+  "options.statisticsMaxAgeDays": "Actualizar si tienen más de (días)",
+  "options.statisticsSampling": "Muestreo",
+  "options.statisticsSamplingDefault": "Predeterminado",
+  "options.statisticsSamplingSample": "Porcentaje",
+  "options.statisticsSamplePercent": "Muestra (%)",
+
+  // Object filters (options panel)
+  "options.objectFilters": "Filtros de Objetos",
+  "options.objectFiltersHint": "Un patrón LIKE por línea (comodines % y _). Los objetos excluidos nunca se modifican.",
+  "options.databaseFilter": "Bases de datos",
+  "options.schemaFilter": "Esquemas",
+  "options.tableFilter": "Tablas",
+  "options.tableFilterHint": "Los patrones con punto coinciden con esquema.tabla",
+  "options.indexFilter": "Índices",
+  "options.filterInclude": "Incluir (vacío = todos)",
+  "options.filterExclude": "Excluir",
+
+  // Overrides (options panel)
+  "options.overrides": "Ajustes por Base de Datos",
+  "options.overridesHint": "Reemplazan los ajustes anteriores para las bases de datos que coinciden, o para las tablas que coinciden si se indica un patrón de tabla. Los campos vacíos se heredan; las entradas posteriores prevalecen.",
+  "options.overrideDatabase": "Patrón de base de datos",
+  "options.overrideTable": "Patrón de tabla (opcional)",
+  "options.overrideInherit": "Heredar",
+  "options.overrideOn": "Sí",
+  "options.overrideOff": "No",
+  "options.overrideReorganizeThreshold": "Reorganizar %",
+  "options.overrideRebuildThreshold": "Reconstruir %",
+  "options.overrideMaxdop": "MAXDOP",
+  "options.overrideFillFactor": "Factor de relleno",
+  "options.overrideOnline": "Online",
+  "options.overrideReorganizeOnly": "Solo reorganizar",
+  "options.overrideCompression": "Compresión",
+  "options.overrideAdd": "Agregar ajuste",
+  "options.overrideRemove": "Quitar ajuste",
+
+  // Maintenance window (options panel)
+  "options.maintenanceWindow": "Ventana de Mantenimiento",
+  "options.windowEndTime": "La ventana termina a las",
+  "options.windowEndTimeHint": "Hora local; vacío = sin hora de fin",
+  "options.maxRunMinutes": "Duración máxima (minutos)",
+  "options.indexPriority": "Orden de procesamiento",
+  "options.indexPriorityHint": "Qué índices van primero cuando la ventana podría no alcanzar para todos",
+  "options.priorityFragmentation": "Más fragmentados",
+  "options.priorityLargestBenefit": "Mayor beneficio",
+  "options.priorityMostUsed": "Más usados",
+  "options.prioritySmallestFirst": "Más pequeños primero",
+  "options.priorityLeastRecentlyMaintained": "Mantenidos hace más tiempo",
 
   // Parallel processing (options panel)
+  "options.logUsage": "Control del Log de Transacciones",
+  "options.logUsageMaxPercent": "Retener operaciones con uso de log (%)",
+  "options.logUsageAction": "Cuando el log está muy lleno",
+  "options.logUsageActionHint": "Se verifica antes de cada operación de índice",
+  "options.logUsageWait": "Esperar un backup de log",
+  "options.logUsagePause": "Pausar la ejecución",
+  "options.availabilityGroups": "Availability Groups",
+  "options.availabilityGroupsHint": "Las bases de datos en una réplica secundaria siempre se omiten. En la primaria, las operaciones esperan mientras la cola de alguna secundaria supere el límite.",
+  "options.agMaxLogSendQueue": "Cola de envío de log máx. (MB)",
+  "options.agMaxRedoQueue": "Cola de redo máx. (MB)",
+  "options.blockingMonitor": "Monitor de Bloqueos",
+  "options.blockingMonitorHint": "Cancela una operación de índice que bloquea a otras sesiones, o queda bloqueada, por demasiado tiempo. Los rebuilds reanudables se pausan; las demás operaciones se matan y hacen rollback. Requiere VIEW SERVER STATE y, para matar, ALTER ANY CONNECTION.",
+  "options.maxBlockingSecs": "Cancelar al bloquear a otros por (s)",
+  "options.maxBlockedSecs": "Cancelar al quedar bloqueada por (s)",
   "options.parallel": "Procesamiento Paralelo",
   "options.parallelDatabases": "Procesar bases de datos en paralelo",
   "options.parallelDatabasesDesc": "Ejecutar múltiples bases de datos de forma concurrente",
@@ -475,6 +800,11 @@ const esAR: Translations = {
   "history.colRebuilt": "Reconstruidos",
   "history.colReorganized": "Reorganizados",
   "history.colSkipped": "Omitidos",
+  "history.colStatistics": "Estadísticas",
+  "history.colDeferred": "Aplazados",
+  "history.statusDeferred": "Aplazada",
+  "history.statusPausedResumable": "Pausada (se reanuda en la próxima ejecución)",
+  "history.statusSkippedByPolicy": "Excluida",
   "history.confirmClear": "Confirmar Borrado",
   "history.cancel": "Cancelar",
   "history.noDetails": "Sin detalles disponibles para esta ejecución.",
@@ -482,6 +812,8 @@ const esAR: Translations = {
   "history.statusStopped": "Detenida",
   "history.statusFailed": "Error",
   "history.statusSkipped": "Omitida",
+  "history.statusLowPriorityAborted": "Cedida (baja prioridad)",
+  "history.statusBlockingAborted": "Abortada: bloqueo",
   "history.colIndex": "Índice",
   "history.colTable": "Tabla",
   "history.colFrag": "Frag%",
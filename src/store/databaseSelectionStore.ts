@@ -1,13 +1,16 @@
 import { create } from "zustand";
+import type { UserDatabase } from "../types";
 
 interface ProfileDatabaseSelection {
   databases: string[];
   selected: string[];
+  /** Availability Group membership by database name; absent when not in an AG. */
+  replicas: Record<string, UserDatabase>;
 }
 
 interface DatabaseSelectionState {
   byProfile: Record<string, ProfileDatabaseSelection>;
-  setDatabasesForProfile: (profileId: string, databases: string[]) => void;
+  setDatabasesForProfile: (profileId: string, databases: UserDatabase[]) => void;
   setSelectedForProfile: (profileId: string, selected: string[]) => void;
   clearProfileSelection: (profileId: string) => void;
 }
@@ -20,6 +23,7 @@ function emptySelection(): ProfileDatabaseSelection {
   return {
     databases: [],
     selected: [],
+    replicas: {},
   };
 }
 
@@ -36,7 +40,10 @@ export const useDatabaseSelectionStore = create<DatabaseSelectionState>((set) =>
   setDatabasesForProfile: (profileId, databases) =>
     set((s) => {
       const current = getProfileSelection(s.byProfile, profileId);
-      const nextDatabases = unique(databases);
+      const nextDatabases = unique(databases.map((db) => db.name));
+      const replicas = Object.fromEntries(
+        databases.filter((db) => db.replica_role !== "none").map((db) => [db.name, db])
+      );
       const hadAllSelected =
         current.databases.length > 0 && current.selected.length === current.databases.length;
 
@@ -52,6 +59,7 @@ export const useDatabaseSelectionStore = create<DatabaseSelectionState>((set) =>
             ...current,
             databases: nextDatabases,
             selected: nextSelected,
+            replicas,
           },
         },
       };
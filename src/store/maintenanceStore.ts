@@ -1,5 +1,6 @@
 import { create } from "zustand";
 import type {
+  AgThrottlePayload,
   DatabaseCardData,
   DbCompletePayload,
   DbStartPayload,
@@ -7,6 +8,8 @@ import type {
   IndexCompletePayload,
   IndexDetail,
   IndexFoundPayload,
+  IndexProgressPayload,
+  LogUsagePayload,
   MaintenanceFinishedPayload,
   MaintenanceSummary,
   RunState,
@@ -29,6 +32,10 @@ interface ProfileRun {
   summary: MaintenanceSummary | null;
   startedAtMs: number;
   isParallel: boolean;
+  /** Databases whose index operations are held by the log usage guard. */
+  logHolds: Record<string, LogUsagePayload>;
+  /** Databases whose index operations are held until their AG queues drain. */
+  agHolds: Record<string, AgThrottlePayload>;
 }
 
 interface MaintenanceState {
@@ -42,14 +49,18 @@ interface MaintenanceState {
   handleDbStart: (payload: DbStartPayload) => void;
   handleIndexFound: (payload: IndexFoundPayload) => void;
   handleIndexAction: (payload: IndexActionPayload) => void;
+  handleIndexProgress: (payload: IndexProgressPayload) => void;
   handleIndexComplete: (payload: IndexCompletePayload) => void;
   handleDbComplete: (payload: DbCompletePayload) => void;
   handleFinished: (payload: MaintenanceFinishedPayload) => void;
+  handleLogUsage: (payload: LogUsagePayload) => void;
+  handleAgThrottle: (payload: AgThrottlePayload) => void;
   handleStopSignal: (profileId: string) => void;
 }
 
-function indexKey(schema: string, table: string, index: string): string {
-  return `${schema}\u0000${table}\u0000${index}`;
+// Partition-level operations report one row per partition of the same index.
+function indexKey(schema: string, table: string, index: string, partition?: number): string {
+  return `${schema}\u0000${table}\u0000${index}\u0000${partition ?? ""}`;
 }
 
 function makeEmptyCard(name: string): DatabaseCardDataInternal {
@@ -80,6 +91,8 @@ function createRun(profileId: string): ProfileRun {
     summary: null,
     startedAtMs: Date.now(),
     isParallel: false,
+    logHolds: {},
+    agHolds: {},
   };
 }
 
@@ -113,6 +126,15 @@ function ensureDb(
   return { run: nextRun, db, dbIdx };
 }
 
+function withoutHolds(run: ProfileRun, name: string): ProfileRun {
+  if (!(name in run.logHolds) && !(name in run.agHolds)) {
+    return run;
+  }
+  const { [name]: _log, ...logHolds } = run.logHolds;
+  const { [name]: _ag, ...agHolds } = run.agHolds;
+  return { ...run, logHolds, agHolds };
+}
+
 function replaceDb(run: ProfileRun, dbIdx: number, nextDb: DatabaseCardDataInternal): ProfileRun {
   if (run.databases[dbIdx] === nextDb) {
     return run;
@@ -137,7 +159,12 @@ function addIndexIfMissing(
   db: DatabaseCardDataInternal,
   detail: IndexDetail
 ): DatabaseCardDataInternal {
-  const key = indexKey(detail.schema_name, detail.table_name, detail.index_name);
+  const key = indexKey(
+    detail.schema_name,
+    detail.table_name,
+    detail.index_name,
+    detail.partition_count > 1 ? detail.partition_number : undefined
+  );
   if (db.indexLookup[key] != null) {
     return db;
   }
@@ -154,9 +181,10 @@ function updateIndexIfFound(
   schema: string,
   table: string,
   index: string,
+  partition: number | undefined,
   updater: (idx: IndexDetail) => IndexDetail
 ): DatabaseCardDataInternal {
-  const idxPos = db.indexLookup[indexKey(schema, table, index)];
+  const idxPos = db.indexLookup[indexKey(schema, table, index, partition)];
   if (idxPos == null) {
     return db;
   }
@@ -210,6 +238,8 @@ export const useMaintenanceStore = create<MaintenanceState>((set) => ({
           summary: null,
           startedAtMs: Date.now(),
           isParallel,
+          logHolds: {},
+          agHolds: {},
         },
       },
     }));
@@ -279,12 +309,30 @@ export const useMaintenanceStore = create<MaintenanceState>((set) => ({
             payload.schema_name,
             payload.table_name,
             payload.index_name,
+            payload.partition_number,
             (idx) => ({ ...idx, action: payload.action, status: "processing" })
           )
         )
       ),
     })),
 
+  handleIndexProgress: (payload) =>
+    set((state) => ({
+      byProfile: withRun(state.byProfile, payload.profile_id, (run) =>
+        withDb(run, payload.db_name, (db) =>
+          updateIndexIfFound(
+            db,
+            payload.schema_name,
+            payload.table_name,
+            payload.index_name,
+            payload.partition_number,
+            // A poll can land just after the operation completed.
+            (idx) => (idx.status === "processing" ? { ...idx, progress: payload.progress } : idx)
+          )
+        )
+      ),
+    })),
+
   handleIndexComplete: (payload) =>
     set((state) => ({
       byProfile: withRun(state.byProfile, payload.profile_id, (run) =>
@@ -295,13 +343,25 @@ export const useMaintenanceStore = create<MaintenanceState>((set) => ({
             payload.schema_name,
             payload.table_name,
             payload.index_name,
+            payload.partition_number,
             (idx) => ({
               ...idx,
-              status: isSkip ? "skipped" : payload.success ? "done" : "error",
+              status: isSkip
+                ? "skipped"
+                : payload.outcome === "deferred"
+                ? "deferred"
+                : payload.outcome === "pausedResumable"
+                ? "paused"
+                : payload.success
+                ? "done"
+                : payload.outcome === "lowPriorityAborted" || payload.outcome === "blockingAborted"
+                ? "aborted"
+                : "error",
               action: payload.action,
               duration_secs: payload.duration_secs,
               retry_attempts: payload.retry_attempts,
               error: payload.error,
+              reason: payload.reason,
             })
           );
 
@@ -309,11 +369,12 @@ export const useMaintenanceStore = create<MaintenanceState>((set) => ({
             ...dbWithIndex,
             indexes_processed: db.indexes_processed + 1,
             indexes_rebuilt:
-              payload.action === "REBUILD" && payload.success
+              (payload.action === "REBUILD" || payload.action === "REBUILD_HEAP") &&
+              payload.outcome === "completed"
                 ? db.indexes_rebuilt + 1
                 : db.indexes_rebuilt,
             indexes_reorganized:
-              payload.action === "REORGANIZE" && payload.success
+              payload.action === "REORGANIZE" && payload.outcome === "completed"
                 ? db.indexes_reorganized + 1
                 : db.indexes_reorganized,
             indexes_skipped: isSkip ? db.indexes_skipped + 1 : db.indexes_skipped,
@@ -325,7 +386,7 @@ export const useMaintenanceStore = create<MaintenanceState>((set) => ({
   handleDbComplete: (payload) =>
     set((state) => ({
       byProfile: withRun(state.byProfile, payload.profile_id, (run) =>
-        withDb(run, payload.result.database_name, (db) => {
+        withDb(withoutHolds(run, payload.result.database_name), payload.result.database_name, (db) => {
           // A database is "interrupted" when it was stopped before all found indexes were
           // processed. A normally-completed database always processes every found index
           // (REBUILD, REORGANIZE, or SKIP), so indexes_processed === indexes.length for
@@ -343,7 +404,7 @@ export const useMaintenanceStore = create<MaintenanceState>((set) => ({
             ...db,
             state: payload.result.interrupted
               ? "stopped"
-              : payload.result.manually_skipped
+              : payload.result.manually_skipped || payload.result.skip_reason
               ? "skipped"
               : payload.result.critical_failure
               ? "error"
@@ -376,6 +437,30 @@ export const useMaintenanceStore = create<MaintenanceState>((set) => ({
           ...run,
           summary: payload.summary,
           runState: nextState,
+          logHolds: {},
+          agHolds: {},
+        };
+      }),
+    })),
+
+  handleLogUsage: (payload) =>
+    set((state) => ({
+      byProfile: withRun(state.byProfile, payload.profile_id, (run) => {
+        const { [payload.db_name]: _cleared, ...logHolds } = run.logHolds;
+        return {
+          ...run,
+          logHolds: payload.state === "cleared" ? logHolds : { ...logHolds, [payload.db_name]: payload },
+        };
+      }),
+    })),
+
+  handleAgThrottle: (payload) =>
+    set((state) => ({
+      byProfile: withRun(state.byProfile, payload.profile_id, (run) => {
+        const { [payload.db_name]: _cleared, ...agHolds } = run.agHolds;
+        return {
+          ...run,
+          agHolds: payload.state === "cleared" ? agHolds : { ...agHolds, [payload.db_name]: payload },
         };
       }),
     })),
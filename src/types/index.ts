@@ -5,18 +5,78 @@
 //
 // AuthType values match Rust's #[serde(rename_all = "camelCase")]
 
-export type AuthType = "sqlServer" | "windowsIntegrated" | "windowsCredentials";
+export type AuthType =
+  | "sqlServer"
+  | "windowsCredentials"
+  | "azureAdServicePrincipal"
+  | "azureAdToken";
 
 export interface ServerProfile {
   id: string;
   name: string;
   server: string;
   port: number;
+  instance_name: string;
   auth_type: AuthType;
   username: string;
   password: string;
   encrypt: boolean;
   trust_server_certificate: boolean;
+  tenant_id: string;
+  authority_host?: string;
+}
+
+export interface ConnectionTestResult {
+  auth_type: AuthType;
+  /** Absent when the login lacks VIEW SERVER STATE to read it. */
+  auth_scheme?: string;
+  login_name: string;
+}
+
+export type OnlineFallback = "offline" | "skip";
+
+export type DataCompression = "KEEP" | "NONE" | "ROW" | "PAGE";
+
+export type AbortAfterWait = "NONE" | "SELF" | "BLOCKERS";
+
+export type StatisticsSampling = "DEFAULT" | "SAMPLE" | "RESAMPLE" | "FULLSCAN";
+
+export type IndexPriority =
+  | "fragmentation"
+  | "largestBenefit"
+  | "mostUsed"
+  | "smallestFirst"
+  | "leastRecentlyMaintained";
+
+export type ScanMode = "LIMITED" | "SAMPLED" | "DETAILED";
+
+export type LogUsageAction = "wait" | "pause";
+
+export type ReplicaRole = "none" | "primary" | "secondary" | "resolving" | "unknown";
+
+export interface UserDatabase {
+  name: string;
+  replica_role: ReplicaRole;
+  availability_group?: string;
+}
+
+/** LIKE-style name patterns (`%`, `_`, case-insensitive); exclude wins over include. */
+export interface PatternFilter {
+  include: string[];
+  exclude: string[];
+}
+
+/** Replaces run-wide options for matching databases (or `table` patterns); unset fields inherit. */
+export interface OptionOverride {
+  database: string;
+  table: string;
+  rebuild_threshold?: number | null;
+  reorganize_threshold?: number | null;
+  rebuild_online?: boolean | null;
+  reorganize_only?: boolean | null;
+  maxdop?: number | null;
+  fill_factor?: number | null;
+  data_compression?: DataCompression | null;
 }
 
 export interface MaintenanceOptions {
@@ -27,10 +87,58 @@ export interface MaintenanceOptions {
   retry_max_attempts: number;
   retry_base_delay_ms: number;
   retry_max_delay_ms: number;
+  retryable_error_numbers: number[];
+  retry_timeouts: boolean;
+  retry_connection_errors: boolean;
   connection_timeout_ms: number;
   request_timeout_ms: number;
+  lock_timeout_ms: number;
+  deadlock_priority_low: boolean;
+  application_name: string;
   parallel_databases: boolean;
   max_parallel_databases: number;
+  scan_mode: ScanMode;
+  page_density_threshold: number;
+  min_page_count: number;
+  max_page_count: number;
+  columnstore_rebuild_threshold: number;
+  columnstore_reorganize_threshold: number;
+  columnstore_delta_rowgroup_threshold: number;
+  include_heaps: boolean;
+  heap_forwarded_record_threshold: number;
+  heap_fragmentation_threshold: number;
+  online_fallback: OnlineFallback;
+  resumable_rebuild: boolean;
+  resumable_max_duration_minutes: number;
+  maxdop: number;
+  sort_in_tempdb: boolean;
+  fill_factor: number;
+  pad_index: boolean;
+  data_compression: DataCompression;
+  low_priority_wait: boolean;
+  low_priority_max_duration_minutes: number;
+  abort_after_wait: AbortAfterWait;
+  update_statistics: boolean;
+  statistics_modification_threshold: number;
+  statistics_max_age_days: number;
+  statistics_sampling: StatisticsSampling;
+  statistics_sample_percent: number;
+  include_column_statistics: boolean;
+  window_end_time: string;
+  max_run_minutes: number;
+  index_priority: IndexPriority;
+  database_filter: PatternFilter;
+  schema_filter: PatternFilter;
+  table_filter: PatternFilter;
+  index_filter: PatternFilter;
+  reorganize_only: boolean;
+  overrides: OptionOverride[];
+  log_usage_max_percent: number;
+  log_usage_action: LogUsageAction;
+  ag_max_log_send_queue_mb: number;
+  ag_max_redo_queue_mb: number;
+  max_blocking_secs: number;
+  max_blocked_secs: number;
 }
 
 export const DEFAULT_OPTIONS: MaintenanceOptions = {
@@ -41,12 +149,76 @@ export const DEFAULT_OPTIONS: MaintenanceOptions = {
   retry_max_attempts: 3,
   retry_base_delay_ms: 1000,
   retry_max_delay_ms: 30000,
+  retryable_error_numbers: [1205, 1222, 4221, 10928, 10929, 40197, 40501, 40613, 49918, 49919, 49920],
+  retry_timeouts: true,
+  retry_connection_errors: true,
   connection_timeout_ms: 30000,
   request_timeout_ms: 0,
+  lock_timeout_ms: 0,
+  deadlock_priority_low: true,
+  application_name: "Indexxorcist",
   parallel_databases: false,
   max_parallel_databases: 4,
+  scan_mode: "LIMITED",
+  page_density_threshold: 0,
+  min_page_count: 100,
+  max_page_count: 0,
+  columnstore_rebuild_threshold: 50,
+  columnstore_reorganize_threshold: 20,
+  columnstore_delta_rowgroup_threshold: 0,
+  include_heaps: false,
+  heap_forwarded_record_threshold: 10,
+  heap_fragmentation_threshold: 0,
+  online_fallback: "offline",
+  resumable_rebuild: false,
+  resumable_max_duration_minutes: 0,
+  maxdop: 0,
+  sort_in_tempdb: false,
+  fill_factor: 0,
+  pad_index: false,
+  data_compression: "KEEP",
+  low_priority_wait: false,
+  low_priority_max_duration_minutes: 5,
+  abort_after_wait: "SELF",
+  update_statistics: false,
+  statistics_modification_threshold: 10,
+  statistics_max_age_days: 0,
+  statistics_sampling: "DEFAULT",
+  statistics_sample_percent: 25,
+  include_column_statistics: false,
+  window_end_time: "",
+  max_run_minutes: 0,
+  index_priority: "fragmentation",
+  database_filter: { include: [], exclude: [] },
+  schema_filter: { include: [], exclude: [] },
+  table_filter: { include: [], exclude: [] },
+  index_filter: { include: [], exclude: [] },
+  reorganize_only: false,
+  overrides: [],
+  log_usage_max_percent: 0,
+  log_usage_action: "wait",
+  ag_max_log_send_queue_mb: 0,
+  ag_max_redo_queue_mb: 0,
+  max_blocking_secs: 0,
+  max_blocked_secs: 0,
 };
 
+export type IndexKind =
+  | "rowstore"
+  | "heap"
+  | "xml"
+  | "spatial"
+  | "clusteredColumnstore"
+  | "nonclusteredColumnstore";
+
+export interface ColumnstoreStats {
+  total_rows: number;
+  deleted_rows: number;
+  open_row_groups: number;
+  closed_row_groups: number;
+  compressed_row_groups: number;
+}
+
 export interface IndexInfo {
   database_name: string;
   schema_name: string;
@@ -54,21 +226,65 @@ export interface IndexInfo {
   index_name: string;
   fragmentation_percent: number;
   page_count: number;
+  page_density_percent?: number;
+  record_count?: number;
+  ghost_record_count?: number;
+  partition_number: number;
+  partition_count: number;
+  forwarded_record_count?: number;
+  index_kind: IndexKind;
+  has_legacy_lob: boolean;
+  has_max_lob: boolean;
+  paused_rebuild_percent?: number;
+  columnstore?: ColumnstoreStats;
+  excluded_by?: string;
 }
 
-export type MaintenanceAction = "REBUILD" | "REORGANIZE" | "SKIP";
+export type MaintenanceAction = "REBUILD" | "REORGANIZE" | "REBUILD_HEAP" | "SKIP";
+
+export type IndexOutcome =
+  | "completed"
+  | "skipped"
+  | "failed"
+  | "lowPriorityAborted"
+  | "deferred"
+  | "skippedByPolicy"
+  | "blockingAborted"
+  | "pausedResumable";
 
 export interface IndexResult {
   schema_name: string;
   table_name: string;
   index_name: string;
+  partition_number?: number;
   fragmentation_percent: number;
   page_count: number;
+  forwarded_record_count?: number;
   action: MaintenanceAction;
   success: boolean;
+  outcome?: IndexOutcome;
+  duration_secs: number;
+  retry_attempts: number;
+  error?: string;
+  /** SQL Server error number of `error`, when the server raised it. */
+  error_number?: number;
+  reason?: string;
+}
+
+export interface StatisticsResult {
+  schema_name: string;
+  table_name: string;
+  stats_name: string;
+  is_index_statistics: boolean;
+  rows?: number;
+  modification_counter?: number;
+  last_updated?: string;
+  sampling: StatisticsSampling;
+  success: boolean;
   duration_secs: number;
   retry_attempts: number;
   error?: string;
+  reason: string;
 }
 
 export interface DatabaseResult {
@@ -78,12 +294,16 @@ export interface DatabaseResult {
   indexes_rebuilt: number;
   indexes_reorganized: number;
   indexes_skipped: number;
+  indexes_deferred?: number;
   total_duration_secs: number;
   errors: string[];
   critical_failure: boolean;
   manually_skipped: boolean;
+  skip_reason?: string;
   interrupted: boolean;
   index_results: IndexResult[];
+  statistics_updated?: number;
+  statistics_results?: StatisticsResult[];
 }
 
 export interface MaintenanceSummary {
@@ -93,10 +313,56 @@ export interface MaintenanceSummary {
   total_indexes_rebuilt: number;
   total_indexes_reorganized: number;
   total_indexes_skipped: number;
+  total_indexes_deferred: number;
   total_duration_secs: number;
+  min_page_count: number;
+  max_page_count: number;
   database_results: DatabaseResult[];
 }
 
+export interface IndexPlan {
+  schema_name: string;
+  table_name: string;
+  index_name: string;
+  partition_number?: number;
+  fragmentation_percent: number;
+  page_count: number;
+  page_density_percent?: number;
+  action: MaintenanceAction;
+  sql?: string;
+  /** UPDATE STATISTICS run right after `sql` when the statistics pass is off. */
+  statistics_sql?: string;
+  reason?: string;
+  /** A run started now would defer it; `reason` says why. */
+  deferred: boolean;
+}
+
+export interface StatisticsPlan {
+  schema_name: string;
+  table_name: string;
+  stats_name: string;
+  rows?: number;
+  modification_counter?: number;
+  last_updated?: string;
+  sql: string;
+  reason: string;
+}
+
+export interface DatabasePlan {
+  database_name: string;
+  indexes: IndexPlan[];
+  statistics: StatisticsPlan[];
+  error?: string;
+}
+
+export interface MaintenancePlan {
+  profile_id: string;
+  server: string;
+  generated_at: string;
+  options: MaintenanceOptions;
+  database_plans: DatabasePlan[];
+}
+
 export interface RunRecord {
   id: number;
   profile_id: string;
@@ -110,7 +376,10 @@ export interface RunRecord {
   total_indexes_rebuilt: number;
   total_indexes_reorganized: number;
   total_indexes_skipped: number;
+  total_indexes_deferred: number;
   total_duration_secs: number;
+  min_page_count: number;
+  max_page_count: number;
   database_results: DatabaseResult[];
 }
 
@@ -118,7 +387,15 @@ export interface RunRecord {
 
 export type DatabaseCardState = "queued" | "running" | "done" | "error" | "skipped" | "stopped";
 
-export type IndexStatus = "pending" | "processing" | "done" | "skipped" | "error";
+export type IndexStatus =
+  | "pending"
+  | "processing"
+  | "done"
+  | "skipped"
+  | "error"
+  | "aborted"
+  | "deferred"
+  | "paused";
 
 export interface IndexDetail extends IndexInfo {
   status: IndexStatus;
@@ -126,6 +403,9 @@ export interface IndexDetail extends IndexInfo {
   duration_secs?: number;
   retry_attempts?: number;
   error?: string;
+  reason?: string;
+  /** Latest progress reported while the operation runs. */
+  progress?: IndexProgress;
 }
 
 export interface DatabaseCardData {
@@ -155,20 +435,46 @@ export interface IndexActionPayload {
   schema_name: string;
   table_name: string;
   index_name: string;
+  partition_number?: number;
   action: MaintenanceAction;
 }
 
+export interface IndexProgress {
+  percent_complete: number;
+  estimated_remaining_secs: number;
+  wait_type?: string;
+  wait_secs: number;
+  cpu_time_ms: number;
+  logical_reads: number;
+  reads: number;
+  writes: number;
+  elapsed_secs: number;
+}
+
+export interface IndexProgressPayload {
+  profile_id: string;
+  db_name: string;
+  schema_name: string;
+  table_name: string;
+  index_name: string;
+  partition_number?: number;
+  progress: IndexProgress;
+}
+
 export interface IndexCompletePayload {
   profile_id: string;
   db_name: string;
   schema_name: string;
   table_name: string;
   index_name: string;
+  partition_number?: number;
   action: MaintenanceAction;
   success: boolean;
+  outcome: IndexOutcome;
   duration_secs: number;
   retry_attempts: number;
   error?: string;
+  reason?: string;
 }
 
 export interface MaintenanceControlPayload {
@@ -191,6 +497,34 @@ export interface MaintenanceFinishedPayload {
   summary: MaintenanceSummary;
 }
 
+export interface LogSpaceUsage {
+  used_percent: number;
+  total_mb: number;
+  log_reuse_wait: string;
+}
+
+export interface LogUsagePayload {
+  profile_id: string;
+  db_name: string;
+  state: "waiting" | "paused" | "cleared";
+  usage: LogSpaceUsage;
+  max_percent: number;
+}
+
+export interface AgQueues {
+  log_send_queue_mb: number;
+  redo_queue_mb: number;
+}
+
+export interface AgThrottlePayload {
+  profile_id: string;
+  db_name: string;
+  state: "waiting" | "cleared";
+  queues: AgQueues;
+  max_log_send_queue_mb: number;
+  max_redo_queue_mb: number;
+}
+
 export interface MaintenanceErrorPayload {
   profile_id: string;
   message: string;